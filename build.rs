@@ -0,0 +1,55 @@
+//! Generates the list of solution modules that the runner binary links in, see `template::registry`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // days are named `NN.rs`, or `YYYY-NN.rs` for solutions that belong to a specific year.
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            let day = stem.split_once('-').map_or(stem, |(year, day)| {
+                if year.len() == 4 && is_number(year) {
+                    day
+                } else {
+                    ""
+                }
+            });
+            let is_day = path.extension()? == "rs" && day.len() == 2 && is_number(day);
+            is_day.then(|| stem.to_string())
+        })
+        .collect();
+    days.sort();
+
+    let mut code = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        let module = module_name(day);
+        code.push_str(&format!(
+            "#[allow(dead_code)]\n#[path = {path:?}]\nmod {module};\n"
+        ));
+    }
+
+    code.push_str("\npub static SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for day in &days {
+        code.push_str(&format!("    {}::SOLUTION,\n", module_name(day)));
+    }
+    code.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), code).unwrap();
+}
+
+fn is_number(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn module_name(bin: &str) -> String {
+    format!("day_{}", bin.replace('-', "_"))
+}
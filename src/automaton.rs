@@ -0,0 +1,248 @@
+//! A cellular automaton that steps a [`Grid`] by a rule on the number of neighbours of each cell.
+//!
+//! ```ignore
+//! use advent_of_code::automaton::{Automaton, Neighbourhood, Update};
+//!
+//! // remove every roll of paper with fewer than four rolls around it, until none is left to remove.
+//! let mut removal = Automaton::new(grid, Neighbourhood::Eight, Update::InPlace, |&c| c == '@', |&c, n| {
+//!     (c == '@' && n < 4).then_some('.')
+//! });
+//! let removed = removal.run_until_stable().changes;
+//! ```
+use crate::grid::{Grid, NEIGHBOURS_4, NEIGHBOURS_8, Point};
+
+/// The cells around a cell that a rule counts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four cells that share an edge.
+    Four,
+    /// The eight cells that share an edge or a corner.
+    Eight,
+}
+
+impl Neighbourhood {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighbourhood::Four => &NEIGHBOURS_4,
+            Neighbourhood::Eight => &NEIGHBOURS_8,
+        }
+    }
+}
+
+/// When the changes of a step become visible to the other cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Update {
+    /// Every cell sees the grid as it was before the step, changes are applied once all cells are evaluated.
+    DoubleBuffered,
+    /// A change is applied right away and seen by the cells evaluated after it, in reading order.
+    InPlace,
+}
+
+/// The outcome of [`Automaton::run_until_stable`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stable {
+    /// The number of steps that changed at least one cell.
+    pub steps: usize,
+    /// The number of cell changes over all steps.
+    pub changes: usize,
+}
+
+/// A grid that is stepped by `rule`, which gets a cell and the number of its neighbours that `counts`
+/// and returns the new value of the cell, or `None` to keep it.
+///
+/// Only cells that changed in the last step and their neighbours can change in the next one,
+/// so a step only evaluates those instead of the whole grid.
+pub struct Automaton<T, C, R> {
+    grid: Grid<T>,
+    neighbourhood: Neighbourhood,
+    update: Update,
+    counts: C,
+    rule: R,
+    /// The cells to evaluate in the next step, in reading order.
+    worklist: Vec<Point>,
+    /// Whether a cell is already on the worklist.
+    queued: Grid<bool>,
+}
+
+impl<T, C, R> Automaton<T, C, R>
+where
+    T: PartialEq,
+    C: Fn(&T) -> bool,
+    R: Fn(&T, usize) -> Option<T>,
+{
+    /// An automaton whose first step evaluates every cell of `grid`.
+    pub fn new(
+        grid: Grid<T>,
+        neighbourhood: Neighbourhood,
+        update: Update,
+        counts: C,
+        rule: R,
+    ) -> Self {
+        Automaton {
+            worklist: grid.positions().collect(),
+            queued: Grid::filled(grid.width(), grid.height(), true),
+            grid,
+            neighbourhood,
+            update,
+            counts,
+            rule,
+        }
+    }
+
+    #[must_use]
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    #[must_use]
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Apply the rule once and return the number of cells that changed.
+    pub fn step(&mut self) -> usize {
+        let worklist = std::mem::take(&mut self.worklist);
+        for &point in &worklist {
+            self.queued[point] = false;
+        }
+
+        let mut changed = vec![];
+
+        match self.update {
+            Update::DoubleBuffered => {
+                let changes: Vec<_> = worklist
+                    .into_iter()
+                    .filter_map(|point| Some((point, self.next(point)?)))
+                    .collect();
+
+                for (point, value) in changes {
+                    self.grid[point] = value;
+                    changed.push(point);
+                }
+            }
+            Update::InPlace => {
+                for point in worklist {
+                    if let Some(value) = self.next(point) {
+                        self.grid[point] = value;
+                        changed.push(point);
+                    }
+                }
+            }
+        }
+
+        for &point in &changed {
+            self.enqueue(point);
+            for &delta in self.neighbourhood.offsets() {
+                if let Some(neighbour) = self.grid.offset(point, delta) {
+                    self.enqueue(neighbour);
+                }
+            }
+        }
+        self.worklist.sort_unstable_by_key(|&(x, y)| (y, x));
+
+        changed.len()
+    }
+
+    /// Step until no cell changes anymore. A rule that never settles, e.g. one with a cycle, never returns.
+    pub fn run_until_stable(&mut self) -> Stable {
+        let mut stable = Stable::default();
+
+        loop {
+            let changes = self.step();
+            if changes == 0 {
+                return stable;
+            }
+            stable.steps += 1;
+            stable.changes += changes;
+        }
+    }
+
+    /// The new value of a cell, `None` if the rule keeps it as it is.
+    fn next(&self, point: Point) -> Option<T> {
+        let count = self
+            .neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(|&delta| self.grid.offset(point, delta))
+            .filter(|&neighbour| (self.counts)(&self.grid[neighbour]))
+            .count();
+
+        let cell = &self.grid[point];
+        (self.rule)(cell, count).filter(|value| value != cell)
+    }
+
+    fn enqueue(&mut self, point: Point) {
+        if !self.queued[point] {
+            self.queued[point] = true;
+            self.worklist.push(point);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Automaton, Neighbourhood, Stable, Update};
+    use crate::grid::Grid;
+
+    fn life(
+        grid: Grid<char>,
+    ) -> Automaton<char, impl Fn(&char) -> bool, impl Fn(&char, usize) -> Option<char>> {
+        Automaton::new(
+            grid,
+            Neighbourhood::Eight,
+            Update::DoubleBuffered,
+            |&c| c == '#',
+            |&c, n| match (c, n) {
+                ('#', 2 | 3) | ('.', 0..=2 | 4..) => None,
+                ('#', _) => Some('.'),
+                _ => Some('#'),
+            },
+        )
+    }
+
+    #[test]
+    fn steps_double_buffered() {
+        let mut blinker = life(Grid::parse(".....\n..#..\n..#..\n..#..\n.....\n", ".#").unwrap());
+
+        assert_eq!(blinker.step(), 4);
+        assert_eq!(
+            blinker.grid().to_string(),
+            ".....\n.....\n.###.\n.....\n....."
+        );
+        assert_eq!(blinker.step(), 4);
+        assert_eq!(blinker.grid().row(1), &['.', '.', '#', '.', '.']);
+
+        let mut block = life(Grid::parse("##\n##\n", ".#").unwrap());
+        assert_eq!(block.run_until_stable(), Stable::default());
+    }
+
+    #[test]
+    fn steps_in_place() {
+        // a cell becomes '#' next to a '#', which in place spreads through the whole row in one step.
+        let spread = |update| {
+            Automaton::new(
+                Grid::parse("#....\n", ".#").unwrap(),
+                Neighbourhood::Four,
+                update,
+                |&c| c == '#',
+                |_, n| (n > 0).then_some('#'),
+            )
+        };
+
+        let mut in_place = spread(Update::InPlace);
+        assert_eq!(in_place.step(), 4);
+        assert_eq!(in_place.step(), 0);
+
+        let mut double_buffered = spread(Update::DoubleBuffered);
+        assert_eq!(
+            double_buffered.run_until_stable(),
+            Stable {
+                steps: 4,
+                changes: 4
+            }
+        );
+        assert_eq!(double_buffered.into_grid().to_string(), "#####");
+    }
+}
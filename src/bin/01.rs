@@ -1,20 +1,17 @@
+use advent_of_code::parse::{lines, parse_all};
 use nom::{
-    IResult, Parser,
-    character::complete::{newline, one_of, u32},
-    combinator::opt,
-    multi::many1,
-    sequence::{pair, terminated},
+    character::complete::{one_of, u32},
+    sequence::pair,
 };
 
 advent_of_code::solution!(1);
 
-fn parse_input(input: &str) -> IResult<&str, Vec<(char, u32)>> {
-    let mut parser = many1(terminated(pair(one_of("LR"), u32), opt(newline)));
-    parser.parse(input)
+fn parse_input(input: &str) -> Vec<(char, u32)> {
+    parse_all(lines(pair(one_of("LR"), u32)), input).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, values) = parse_input(input).unwrap();
+    let values = parse_input(input);
 
     let mut zeros = 0;
     let mut position = 50;
@@ -34,7 +31,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, values) = parse_input(input).unwrap();
+    let values = parse_input(input);
 
     let mut zeros = 0;
     let mut position = 50;
@@ -48,8 +45,8 @@ pub fn part_two(input: &str) -> Option<u64> {
         let distance = distance % 100;
 
         match direction {
-            'L' => position = position - distance as i32,
-            'R' => position = position + distance as i32,
+            'L' => position -= distance as i32,
+            'R' => position += distance as i32,
             _ => unreachable!(),
         }
 
@@ -1,15 +1,10 @@
-use nom::{
-    IResult, Parser,
-    character::complete::{digit1, newline},
-    combinator::opt,
-    multi::many1,
-    sequence::terminated,
-};
+use advent_of_code::parse::{lines, parse_all};
+use nom::character::complete::digit1;
 
 advent_of_code::solution!(3);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, banks) = parse_input(input).unwrap();
+    let banks = parse_input(input);
 
     let mut total = 0;
 
@@ -21,7 +16,7 @@ pub fn part_one(input: &str) -> Option<u64> {
             .iter()
             .enumerate()
             .max_by(|x, y| {
-                let c = x.1.cmp(&y.1);
+                let c = x.1.cmp(y.1);
                 match c {
                     // prefer first index to get largest possible number after that
                     std::cmp::Ordering::Equal => y.0.cmp(&x.0),
@@ -37,14 +32,12 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(total)
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<&str>> {
-    let mut parser = many1(terminated(digit1, opt(newline)));
-
-    parser.parse(input)
+fn parse_input(input: &str) -> Vec<&str> {
+    parse_all(lines(digit1), input).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, banks) = parse_input(input).unwrap();
+    let banks = parse_input(input);
 
     let mut total = 0;
 
@@ -67,7 +60,7 @@ fn find_max_joltage(bank: &[u8], req_len: usize) -> Option<u64> {
     let mut choices: Vec<(usize, &u8)> =
         bank[0..=bank.len() - req_len].iter().enumerate().collect();
     choices.sort_by(|x, y| {
-        let c = y.1.cmp(&x.1);
+        let c = y.1.cmp(x.1);
         match c {
             // prefer first index to get largest possible number after that
             std::cmp::Ordering::Equal => x.0.cmp(&y.0),
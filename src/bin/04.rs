@@ -1,90 +1,37 @@
-use nom::{
-    IResult, Parser,
-    character::complete::{newline, one_of},
-    combinator::opt,
-    multi::many1,
-    sequence::terminated,
-};
+use advent_of_code::automaton::{Automaton, Neighbourhood, Update};
+use advent_of_code::grid::Grid;
 
 advent_of_code::solution!(4);
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<char>>> {
-    let mut parser = many1(terminated(many1(one_of("@.")), opt(newline)));
-    parser.parse(input)
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input, "@.").unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, grid) = parse_input(input).unwrap();
+    let grid = parse_input(input);
 
-    let accessible = find_accessible(&grid);
-    Some(accessible.len() as u64)
+    Some(removal(grid, Update::DoubleBuffered).step() as u64)
 }
 
-fn find_accessible(grid: &Vec<Vec<char>>) -> Vec<(usize, usize)> {
-    let mut accessible = vec![];
-    for row in 0..grid.len() {
-        let row_length = grid[row].len();
-        for col in 0..row_length {
-            if grid[row][col] == '@' {
-                let mut surrounding_count = 0;
-                if row > 0 {
-                    if col > 0 && grid[row - 1][col - 1] == '@' {
-                        surrounding_count += 1;
-                    }
-                    if grid[row - 1][col] == '@' {
-                        surrounding_count += 1;
-                    }
-                    if col < row_length - 1 && grid[row - 1][col + 1] == '@' {
-                        surrounding_count += 1;
-                    }
-                }
-                if row < grid.len() - 1 {
-                    if col > 0 && grid[row + 1][col - 1] == '@' {
-                        surrounding_count += 1;
-                    }
-                    if grid[row + 1][col] == '@' {
-                        surrounding_count += 1;
-                    }
-                    if col < row_length - 1 && grid[row + 1][col + 1] == '@' {
-                        surrounding_count += 1;
-                    }
-                }
-                if col > 0 && grid[row][col - 1] == '@' {
-                    surrounding_count += 1;
-                }
-                if col < row_length - 1 && grid[row][col + 1] == '@' {
-                    surrounding_count += 1;
-                }
-                if surrounding_count < 4 {
-                    accessible.push((row, col));
-                }
-            }
-        }
-    }
-
-    accessible
-}
-
-fn count_total_accessible(grid: &Vec<Vec<char>>) -> u64 {
-    let accessible = find_accessible(grid);
-    let accessible_count = accessible.len();
-    if accessible.is_empty() {
-        return 0;
-    }
-
-    let mut new_grid: Vec<Vec<char>> = grid.iter().map(|r| r.clone()).collect();
-
-    for (row, col) in accessible {
-        new_grid[row][col] = '.';
-    }
-
-    accessible_count as u64 + count_total_accessible(&new_grid)
+/// Removes the rolls of paper with fewer than four rolls next to them.
+fn removal(
+    grid: Grid<char>,
+    update: Update,
+) -> Automaton<char, impl Fn(&char) -> bool, impl Fn(&char, usize) -> Option<char>> {
+    Automaton::new(
+        grid,
+        Neighbourhood::Eight,
+        update,
+        |&c| c == '@',
+        |&c, rolls| (c == '@' && rolls < 4).then_some('.'),
+    )
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, grid) = parse_input(input).unwrap();
+    let grid = parse_input(input);
 
-    Some(count_total_accessible(&grid))
+    // removing a roll only makes others easier to reach, so the order of removals does not matter.
+    Some(removal(grid, Update::InPlace).run_until_stable().changes as u64)
 }
 
 #[cfg(test)]
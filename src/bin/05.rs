@@ -1,31 +1,28 @@
-use nom::{
-    IResult, Parser,
-    bytes::complete::tag,
-    character::complete::{newline, u64},
-    combinator::opt,
-    multi::many1,
-    sequence::{separated_pair, terminated},
-};
+use std::ops::RangeInclusive;
+
+use advent_of_code::parse::{blank_line, lines, parse_all, range};
+use nom::{character::complete::u64, sequence::separated_pair};
 
 advent_of_code::solution!(5);
 
-fn parse_input(input: &str) -> IResult<&str, (Vec<(u64, u64)>, Vec<u64>)> {
-    let mut parser = separated_pair(
-        many1(terminated(separated_pair(u64, tag("-"), u64), newline)),
-        newline,
-        many1(terminated(u64, opt(newline))),
-    );
-    parser.parse(input)
+type Inventory = (Vec<RangeInclusive<u64>>, Vec<u64>);
+
+fn parse_input(input: &str) -> Inventory {
+    parse_all(
+        separated_pair(lines(range(u64)), blank_line(), lines(u64)),
+        input,
+    )
+    .unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, (fresh, available)) = parse_input(input).unwrap();
+    let (fresh, available) = parse_input(input);
 
     let mut count = 0;
 
     // naïve version for first go
     for a in available {
-        if fresh.iter().any(|&(low, high)| a >= low && a <= high) {
+        if fresh.iter().any(|range| range.contains(&a)) {
             count += 1;
         }
     }
@@ -34,14 +31,14 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, (fresh, _)) = parse_input(input).unwrap();
+    let (fresh, _) = parse_input(input);
 
     let mut sorted_ranges = fresh.clone();
-    sorted_ranges.sort_by(|a, b| a.0.cmp(&b.0));
+    sorted_ranges.sort_by_key(|a| *a.start());
 
     let mut collated_ranges = Vec::new();
 
-    for (low, high) in sorted_ranges {
+    for (low, high) in sorted_ranges.into_iter().map(RangeInclusive::into_inner) {
         if let Some(&(_, prev_high)) = collated_ranges.last() {
             if low > prev_high {
                 // no overlap with previous
@@ -1,11 +1,7 @@
 use std::cmp::min;
 
-use nom::{
-    IResult, Parser,
-    character::complete::{newline, one_of},
-    multi::many1,
-    sequence::{pair, terminated},
-};
+use advent_of_code::parse::{chars, lines, parse_all};
+use nom::{character::complete::line_ending, sequence::separated_pair};
 
 advent_of_code::solution!(6);
 
@@ -15,17 +11,16 @@ enum Op {
     Multiply,
 }
 
-fn parse_input(input: &str) -> IResult<&str, (Vec<Vec<char>>, Vec<char>)> {
-    let mut parser = pair(
-        many1(terminated(many1(one_of(" 0123456789")), newline)),
-        terminated(many1(one_of(" *+")), newline),
-    );
-
-    parser.parse(input)
+fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<char>) {
+    parse_all(
+        separated_pair(lines(chars(" 0123456789")), line_ending, chars(" *+")),
+        input,
+    )
+    .unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, (number_rows, operations)) = parse_input(input).unwrap();
+    let (number_rows, operations) = parse_input(input);
 
     let number_rows: Vec<Vec<u64>> = number_rows
         .iter()
@@ -71,7 +66,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, (number_rows, operations)) = parse_input(input).unwrap();
+    let (number_rows, operations) = parse_input(input);
 
     let mut total = 0;
     let line_len = number_rows.iter().map(|x| x.len()).max().unwrap();
@@ -1,40 +1,35 @@
+use advent_of_code::grid::{Grid, Point};
 use fxhash::{FxHashMap, FxHashSet};
-use nom::{
-    IResult, Parser,
-    character::complete::{newline, one_of},
-    combinator::opt,
-    multi::many1,
-    sequence::terminated,
-};
 
 advent_of_code::solution!(7);
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<char>>> {
-    let mut parser = many1(terminated(many1(one_of("^.S")), opt(newline)));
-    parser.parse(input)
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input, "^.S").unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// The first splitter below a beam at `(x, y)`, if it hits one.
+fn find_splitter(grid: &Grid<char>, (x, y): Point) -> Option<Point> {
+    grid.column(x)
+        .enumerate()
+        .skip(y + 1)
+        .find(|&(_, &c)| c == '^')
+        .map(|(y, _)| (x, y))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, rows) = parse_input(input).unwrap();
+    let grid = parse_input(input);
 
-    // assume start is in first row
-    let start = (0usize, rows[0].iter().position(|&x| x == 'S').unwrap());
+    let start = grid.find(&'S').expect("the manifold should have a start");
 
     let mut beams_to_process = vec![start];
     let mut split_beams = FxHashSet::default();
 
-    while let Some((beam_row, beam_col)) = beams_to_process.pop() {
-        for (idx, r) in rows[beam_row + 1..].iter().enumerate() {
-            let row = beam_row + idx + 1;
-            if r[beam_col] == '^' {
-                let loc = (row, beam_col);
-                if !split_beams.contains(&loc) {
-                    beams_to_process.push((row, beam_col - 1));
-                    beams_to_process.push((row, beam_col + 1));
-                    split_beams.insert(loc);
-                }
-                break;
-            }
+    while let Some(beam) = beams_to_process.pop() {
+        if let Some((x, y)) = find_splitter(&grid, beam)
+            && split_beams.insert((x, y))
+        {
+            beams_to_process.push((x - 1, y));
+            beams_to_process.push((x + 1, y));
         }
     }
 
@@ -42,36 +37,28 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, rows) = parse_input(input).unwrap();
+    let grid = parse_input(input);
 
-    // assume start is in first row
-    let start = (0usize, rows[0].iter().position(|&x| x == 'S').unwrap());
+    let start = grid.find(&'S').expect("the manifold should have a start");
     let mut memo = FxHashMap::default();
 
-    Some(count_timelines(start, &rows, &mut memo))
+    Some(count_timelines(start, &grid, &mut memo))
 }
 
-fn count_timelines(
-    start: (usize, usize),
-    rows: &Vec<Vec<char>>,
-    memo: &mut FxHashMap<(usize, usize), u64>,
-) -> u64 {
+fn count_timelines(start: Point, grid: &Grid<char>, memo: &mut FxHashMap<Point, u64>) -> u64 {
     if let Some(&count) = memo.get(&start) {
         return count;
     }
 
-    let (beam_row, beam_col) = start;
-
-    for (idx, r) in rows[beam_row + 1..].iter().enumerate() {
-        let row = beam_row + idx + 1;
-        if r[beam_col] == '^' {
-            let timelines = count_timelines((row, beam_col - 1), rows, memo)
-                + count_timelines((row, beam_col + 1), rows, memo);
+    match find_splitter(grid, start) {
+        Some((x, y)) => {
+            let timelines =
+                count_timelines((x - 1, y), grid, memo) + count_timelines((x + 1, y), grid, memo);
             memo.insert(start, timelines);
-            return timelines;
+            timelines
         }
+        None => 1,
     }
-    return 1;
 }
 
 #[cfg(test)]
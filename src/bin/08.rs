@@ -1,17 +1,9 @@
-use std::usize;
-
-use fxhash::FxHashSet;
+use advent_of_code::parse::{comma_separated, lines, parse_all};
+use advent_of_code::union_find::UnionFind;
 use itertools::Itertools;
-use nom::{
-    IResult, Parser,
-    bytes::complete::tag,
-    character::complete::{i64, newline},
-    combinator::opt,
-    multi::{many1, separated_list1},
-    sequence::terminated,
-};
+use nom::character::complete::i64;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, params = [max: usize = 1000]);
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 struct Point {
@@ -21,7 +13,7 @@ struct Point {
 }
 
 impl Point {
-    fn from_vec(v: &Vec<i64>) -> Self {
+    fn from_vec(v: &[i64]) -> Self {
         Self {
             x: v[0],
             y: v[1],
@@ -37,85 +29,56 @@ impl Point {
     }
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
-    let mut parser = many1(terminated(separated_list1(tag(","), i64), opt(newline)));
-    parser.parse(input)
+fn parse_input(input: &str) -> Vec<Point> {
+    let locations = parse_all(lines(comma_separated(i64)), input).unwrap_or_else(|e| panic!("{e}"));
+    locations.iter().map(|v| Point::from_vec(v)).collect()
 }
 
-fn find_shortest_distances(locations: &Vec<Vec<i64>>, max: usize) -> Vec<(Point, Point, i64)> {
-    locations
-        .iter()
-        .combinations(2)
-        .map(|v| {
-            let p1 = Point::from_vec(&v[0]);
-            let p2 = Point::from_vec(&v[1]);
-            let distance = p1.distance(&p2);
-            (p1, p2, distance)
-        })
-        .sorted_by_key(|&(_, _, distance)| distance)
+/// The `max` closest pairs of locations, as indices into `locations`.
+fn find_shortest_distances(locations: &[Point], max: usize) -> Vec<(usize, usize)> {
+    (0..locations.len())
+        .tuple_combinations()
+        .sorted_by_cached_key(|&(a, b)| locations[a].distance(&locations[b]))
         .take(max)
         .collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    part_one_max(input, 1000)
-}
-
-fn part_one_max(input: &str, max: usize) -> Option<u64> {
-    let (_, locations) = parse_input(input).unwrap();
+    let locations = parse_input(input);
 
-    let mut circuits: Vec<FxHashSet<Point>> = Vec::new();
-    build_circuits(&locations, &mut circuits, max);
+    let mut circuits = UnionFind::new(locations.len());
+    build_circuits(&locations, &mut circuits, params::max());
 
     circuits
-        .iter()
-        .map(|c| c.len() as u64)
+        .component_sizes()
+        .filter(|&size| size > 1)
+        .map(|size| size as u64)
         .sorted()
         .rev()
         .take(3)
         .reduce(|acc, e| acc * e)
 }
 
+/// Connect the `max` closest pairs of locations, and return the pair that connected all of them into one circuit.
 fn build_circuits(
-    locations: &Vec<Vec<i64>>,
-    circuits: &mut Vec<FxHashSet<Point>>,
+    locations: &[Point],
+    circuits: &mut UnionFind,
     max: usize,
 ) -> Option<(Point, Point)> {
-    let shortest_distance_pairs = find_shortest_distances(&locations, max);
-
-    for (p1, p2, _) in shortest_distance_pairs {
-        let c1 = circuits.iter().enumerate().find(|(_, c)| c.contains(&p1));
-        let c2 = circuits.iter().enumerate().find(|(_, c)| c.contains(&p2));
-
-        if let Some((idx1, c)) = c1 {
-            if let Some((idx2, other)) = c2 {
-                if idx1 != idx2 {
-                    // combine circuits
-                    circuits[idx1] = FxHashSet::from_iter(c.union(other).map(|&x| x));
-                    circuits.remove(idx2);
-                }
-            } else {
-                circuits.get_mut(idx1).unwrap().insert(p2);
-            }
-        } else {
-            if let Some((idx2, _)) = c2 {
-                circuits.get_mut(idx2).unwrap().insert(p1);
-            } else {
-                let c = FxHashSet::from_iter(vec![p1, p2]);
-                circuits.push(c);
-            }
-        }
-        if circuits.len() == 1 && circuits.first()?.len() == locations.len() {
-            return Some((p1, p2));
+    let shortest_distance_pairs = find_shortest_distances(locations, max);
+
+    for (a, b) in shortest_distance_pairs {
+        if circuits.union(a, b) && circuits.components() == 1 {
+            return Some((locations[a], locations[b]));
         }
     }
     None
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, locations) = parse_input(input).unwrap();
+    let locations = parse_input(input);
 
-    let mut circuits: Vec<FxHashSet<Point>> = Vec::new();
+    let mut circuits = UnionFind::new(locations.len());
 
     if let Some((last_p1, last_p2)) = build_circuits(&locations, &mut circuits, usize::MAX) {
         Some((last_p2.x * last_p1.x) as u64)
@@ -127,10 +90,14 @@ pub fn part_two(input: &str) -> Option<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_part_one() {
-        let result = part_one_max(&advent_of_code::template::read_file("examples", DAY), 10);
+        let params = HashMap::from([("max".to_string(), "10".to_string())]);
+        let result = advent_of_code::template::params::with_params(&params, || {
+            part_one(&advent_of_code::template::read_file("examples", DAY))
+        });
         assert_eq!(result, Some(40));
     }
 
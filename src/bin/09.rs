@@ -1,23 +1,20 @@
 use std::cmp::{max, min};
 
+use advent_of_code::parse::{lines, parse_all};
 use itertools::Itertools;
 use nom::{
-    IResult, Parser,
-    bytes::complete::tag,
-    character::complete::{i64, newline},
-    multi::many1,
-    sequence::{separated_pair, terminated},
+    character::complete::{char, i64},
+    sequence::separated_pair,
 };
 
 advent_of_code::solution!(9);
 
-fn parse_input(input: &str) -> IResult<&str, Vec<(i64, i64)>> {
-    let mut parser = many1(terminated(separated_pair(i64, tag(","), i64), newline));
-    parser.parse(input)
+fn parse_input(input: &str) -> Vec<(i64, i64)> {
+    parse_all(lines(separated_pair(i64, char(','), i64)), input).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_one(input: &str) -> Option<i64> {
-    let (_, tiles) = parse_input(input).unwrap();
+    let tiles = parse_input(input);
 
     tiles
         .iter()
@@ -104,10 +101,12 @@ fn is_inside_polygon(tiles: &[(i64, i64)], (x1, y1): (i64, i64), (x2, y2): (i64,
     left && right && up && down
 }
 
+type Rectangle = ((i64, i64), (i64, i64), i64);
+
 pub fn part_two(input: &str) -> Option<i64> {
-    let (_, tiles) = parse_input(input).unwrap();
+    let tiles = parse_input(input);
 
-    let sorted: Vec<((i64, i64), (i64, i64), i64)> = tiles
+    let sorted: Vec<Rectangle> = tiles
         .iter()
         .combinations(2)
         .map(|v| {
@@ -117,7 +116,7 @@ pub fn part_two(input: &str) -> Option<i64> {
             let w = (x2 - x1).abs() + 1;
             ((x1, y1), (x2, y2), w * h)
         })
-        .sorted_by(|(_, _, a), (_, _, b)| b.cmp(&a))
+        .sorted_by(|(_, _, a), (_, _, b)| b.cmp(a))
         .collect();
 
     for ((x1, y1), (x2, y2), a) in sorted {
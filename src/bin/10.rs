@@ -1,34 +1,32 @@
+use advent_of_code::parse::{comma_separated, lines, parse_all};
 use fxhash::FxHashMap;
-use itertools::Itertools;
 use nom::{
-    IResult, Parser,
+    Parser,
     bytes::complete::tag,
-    character::complete::{newline, one_of, u64},
-    combinator::opt,
+    character::complete::{one_of, u64},
     multi::{many1, separated_list1},
-    sequence::{delimited, terminated},
+    sequence::delimited,
 };
 
 advent_of_code::solution!(10);
 
-fn parse_input(input: &str) -> IResult<&str, Vec<(Vec<bool>, Vec<Vec<u64>>, Vec<u64>)>> {
-    let mut parser = many1(terminated(
-        (
-            delimited(tag("["), many1(one_of(".#").map(|x| x == '#')), tag("] ")),
-            separated_list1(
-                tag(" "),
-                delimited(tag("("), separated_list1(tag(","), u64), tag(")")),
-            ),
-            delimited(tag(" {"), separated_list1(tag(","), u64), tag("}")),
+type Machine = (Vec<bool>, Vec<Vec<u64>>, Vec<u64>);
+
+fn parse_input(input: &str) -> Vec<Machine> {
+    let machine = (
+        delimited(tag("["), many1(one_of(".#").map(|x| x == '#')), tag("] ")),
+        separated_list1(
+            tag(" "),
+            delimited(tag("("), comma_separated(u64), tag(")")),
         ),
-        opt(newline),
-    ));
+        delimited(tag(" {"), comma_separated(u64), tag("}")),
+    );
 
-    parser.parse(input)
+    parse_all(lines(machine), input).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (_, machines) = parse_input(input).unwrap();
+    let machines = parse_input(input);
 
     let mut total = 0;
     for (light_diagram, wires, _) in machines {
@@ -84,7 +82,7 @@ fn calculate_min_presses(target_state: usize, wires: &[Vec<u64>], iterations: us
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (_, machines) = parse_input(input).unwrap();
+    let machines = parse_input(input);
 
     let mut total = 0;
     for (_, wires, joltage_requirements) in machines {
@@ -95,16 +93,6 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(total)
 }
 
-fn apply_buttons(buttons: &[&Vec<u64>], state: &Vec<u64>) -> Vec<u64> {
-    let mut result = state.clone();
-    for button in buttons {
-        for i in *button {
-            result[*i as usize] += 1;
-        }
-    }
-    result
-}
-
 fn calculate_min_joltage_presses(joltage_requirements: &Vec<u64>, buttons: &[Vec<u64>]) -> u64 {
     let target_state = joltage_requirements
         .iter()
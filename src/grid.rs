@@ -0,0 +1,366 @@
+//! A rectangular grid of cells, the shape of many puzzle inputs.
+//!
+//! ```ignore
+//! use advent_of_code::grid::Grid;
+//!
+//! let grid = Grid::parse("#.\n.#\n", ".#")?;
+//! let walls = grid.neighbours8((0, 0)).filter(|&p| grid[p] == '#').count();
+//! ```
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::template::ParseError;
+
+/// A position in a grid as `(x, y)`, where `(0, 0)` is the top left cell.
+pub type Point = (usize, usize);
+
+/// The offsets of the four neighbours that share an edge with a cell, clockwise from the one above.
+pub(crate) const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the eight neighbours that share an edge or a corner with a cell, in reading order.
+pub(crate) const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells, row by row.
+    ///
+    /// # Panics
+    /// If the number of cells is not `width * height`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A grid with every cell set to `value`.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parse a grid with one row per line, turning every character into a cell with `cell`.
+    /// A character that `cell` returns `None` for and lines that are longer or shorter than
+    /// the first one are errors. A trailing line break is ignored.
+    pub fn parse_with(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        let mut offset = 0;
+
+        for line in input.trim_end_matches(['\r', '\n']).split_inclusive('\n') {
+            let row = line.trim_end_matches(['\r', '\n']);
+            let mut row_width = 0;
+
+            for (i, c) in row.char_indices() {
+                let Some(cell) = cell(c) else {
+                    let message = format!("unexpected {c:?} in grid");
+                    return Err(ParseError::at(input, &input[offset + i..], message));
+                };
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                // point at the first missing or extra cell.
+                let i = row
+                    .char_indices()
+                    .nth(width.min(row_width))
+                    .map_or(row.len(), |(i, _)| i);
+                let message = format!("expected a row of {width} cells, found {row_width}");
+                return Err(ParseError::at(input, &input[offset + i..], message));
+            }
+
+            height += 1;
+            offset += line.len();
+        }
+
+        Ok(Grid::new(width.unwrap_or_default(), height, cells))
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The cell at a position, `None` if it is outside of the grid.
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.1 * self.width + point.0])
+    }
+
+    /// The position `(dx, dy)` away from `point`, if it lies inside the grid.
+    #[must_use]
+    pub fn offset(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let point = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(point).then_some(point)
+    }
+
+    /// The up to four neighbours of a position that share an edge with it, clockwise from the one above.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(point, delta))
+    }
+
+    /// The up to eight neighbours of a position, including the diagonal ones, in reading order.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(point, delta))
+    }
+
+    /// Every position of the grid in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    /// If the row is outside of the grid.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero, which an empty grid would use.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    ///
+    /// # Panics
+    /// If the column is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside of a grid of width {}",
+            self.width
+        );
+
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The grid mirrored along its diagonal, so that its rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(
+            self.height,
+            self.width,
+            self.columns().flatten().cloned().collect(),
+        )
+    }
+
+    /// The position of the first cell with `value`, in reading order.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(p, _)| p)
+    }
+
+    /// The positions of all cells with `value`, in reading order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(p, _)| p)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    #[must_use]
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters from `alphabet`, with one row per line.
+    pub fn parse(input: &str, alphabet: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, |c| alphabet.contains(c).then_some(c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+/// Renders the grid in the format [`Grid::parse`] reads, one row per line without a trailing line break.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    const INPUT: &str = "#..\n.#.\n#.#\n..#\n";
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::parse(INPUT, ".#").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT.trim_end());
+    }
+
+    #[test]
+    fn reports_malformed_grids() {
+        let error = Grid::parse("#..\n.x.\n", ".#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Grid::parse("#..\n.#\n", ".#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "expected a row of 3 cells, found 2");
+
+        let error = Grid::parse("#..\r\n.#..\r\n", ".#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        assert_eq!(Grid::parse("", ".#").unwrap().height(), 0);
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::parse(INPUT, ".#").unwrap();
+
+        assert_eq!(grid.row(2), &['#', '.', '#']);
+        assert_eq!(grid.rows().count(), 4);
+        assert_eq!(grid.column(2).collect::<String>(), "..##");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.transpose().to_string(), "#.#.\n.#..\n..##");
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of a grid of width 3")]
+    fn rejects_columns_outside_of_the_grid() {
+        let grid = Grid::parse(INPUT, ".#").unwrap();
+
+        let _ = grid.column(3);
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = Grid::parse(INPUT, ".#").unwrap();
+
+        assert_eq!(grid.find(&'#'), Some((0, 0)));
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1), (0, 2), (2, 2), (2, 3)]
+        );
+        assert_eq!(grid.map(|&c| c == '#').find(&true), Some((0, 0)));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod automaton;
+pub mod grid;
+pub mod parse;
 pub mod template;
+pub mod union_find;
 
 // Use this file to add helper functions and additional modules.
@@ -1,81 +1,229 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    accept, all, download, examples, read, run_day, scaffold, solve, time,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
+#[cfg(not(test))]
+use advent_of_code::template::registry;
 #[cfg(feature = "today")]
 use std::process;
 
+/// Every solution in `src/bin`, linked in so that `all` and `time` can run them in-process.
+#[cfg(not(test))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{
+        Day, Year,
+        commands::solve::SolveOptions,
+        params, registry,
+        runner::{BenchOptions, Limits, RunOptions},
+    };
     use std::process;
+    use std::time::Duration;
 
     pub enum AppArguments {
+        Accept {
+            year: Year,
+            day: Day,
+            part: u8,
+            answer: String,
+        },
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Examples {
+            year: Year,
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            template: String,
         },
         Solve {
+            year: Year,
             day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            options: SolveOptions,
         },
         All {
-            release: bool,
+            years: Vec<Year>,
+            verify: bool,
+            options: RunOptions,
         },
         Time {
+            years: Vec<Year>,
             all: bool,
             day: Option<Day>,
             store: bool,
+            memory: bool,
+            options: RunOptions,
+            threshold: f64,
+        },
+        TimeHistory {
+            year: Year,
+            day: Day,
+        },
+        RunDay {
+            year: Year,
+            day: Day,
+            options: RunOptions,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
+            Some("accept") => {
+                let (year, day) = parse_day(&mut args)?;
+                AppArguments::Accept {
+                    year,
+                    day,
+                    part: args.free_from_str()?,
+                    answer: args.free_from_str()?,
+                }
+            }
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                years: parse_years(&mut args)?,
+                verify: args.contains("--verify"),
+                options: RunOptions {
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    limits: parse_limits(&mut args)?,
+                    jobs: parse_jobs(&mut args)?,
+                    ..RunOptions::default()
+                },
             },
             Some("time") => {
+                if let Some(day) = args.opt_value_from_str::<_, String>("--history")? {
+                    let year = parse_year(&mut args)?;
+                    let day = Day::parse_in(&day, year)?;
+                    return finish(args, AppArguments::TimeHistory { year, day });
+                }
+
+                let years = parse_years(&mut args)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+
+                let defaults = BenchOptions::default();
+                let bench = BenchOptions {
+                    warmup: args
+                        .opt_value_from_str("--warmup")?
+                        .unwrap_or(defaults.warmup),
+                    min_time: args
+                        .opt_value_from_str("--min-time")?
+                        .map_or(defaults.min_time, Duration::from_millis),
+                    max_time: args
+                        .opt_value_from_str("--max-time")?
+                        .map_or(defaults.max_time, Duration::from_millis),
+                };
+
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+
+                let limits = parse_limits(&mut args)?;
+
+                if args.opt_value_from_str::<_, String>("--jobs")?.is_some() {
+                    return Err(
+                        "`time` does not accept `--jobs`, benchmarks run one day at a time.".into(),
+                    );
+                }
+
+                let day = match args.opt_free_from_str::<String>()? {
+                    Some(day) => Some(parse_day_in(&day, &years)?),
+                    None => None,
+                };
 
                 AppArguments::Time {
+                    years,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day,
                     store,
+                    memory,
+                    options: RunOptions {
+                        format,
+                        bench,
+                        limits,
+                        ..RunOptions::default()
+                    },
+                    threshold,
+                }
+            }
+            // not meant to be called directly, `all` and `time` run days with a time limit in a child process.
+            Some("run-day") => {
+                let (year, day) = parse_day(&mut args)?;
+                // the remaining arguments are the run options, as read by `RunOptions::from_args`.
+                return Ok(AppArguments::RunDay {
+                    year,
+                    day,
+                    options: RunOptions::from_args(),
+                });
+            }
+            Some("download") => {
+                let (year, day) = parse_day(&mut args)?;
+                AppArguments::Download { year, day }
+            }
+            Some("read") => {
+                let (year, day) = parse_day(&mut args)?;
+                AppArguments::Read { year, day }
+            }
+            Some("examples") => {
+                let (year, day) = parse_day(&mut args)?;
+                AppArguments::Examples {
+                    year,
+                    day,
+                    overwrite: args.contains("--overwrite"),
+                }
+            }
+            Some("scaffold") => {
+                let (year, day) = parse_day(&mut args)?;
+                AppArguments::Scaffold {
+                    year,
+                    day,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                    template: args
+                        .opt_value_from_str("--template")?
+                        .unwrap_or_else(|| "default".into()),
+                }
+            }
+            Some("solve") => {
+                let (year, day) = parse_day(&mut args)?;
+                AppArguments::Solve {
+                    year,
+                    day,
+                    options: SolveOptions {
+                        release: args.contains("--release"),
+                        submit: args.opt_value_from_str("--submit")?,
+                        dhat: args.contains("--dhat"),
+                        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                        examples: args.contains("--examples"),
+                        params: args.values_from_fn("--param", params::parse_param)?,
+                        watch: args.contains("--watch"),
+                    },
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                year: parse_year(&mut args)?,
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -86,6 +234,78 @@ mod args {
             }
         };
 
+        finish(args, app_args)
+    }
+
+    /// The year passed with `--year`, or the configured `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => configured_year(),
+        }
+    }
+
+    /// The year and the day, which has to be part of the year's event.
+    fn parse_day(
+        args: &mut pico_args::Arguments,
+    ) -> Result<(Year, Day), Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day = Day::parse_in(&args.free_from_str::<String>()?, year)?;
+
+        Ok((year, day))
+    }
+
+    /// A day that has to be part of the event of every one of `years`.
+    fn parse_day_in(day: &str, years: &[Year]) -> Result<Day, Box<dyn std::error::Error>> {
+        let mut parsed: Day = day.parse()?;
+        for &year in years {
+            parsed = Day::parse_in(day, year)?;
+        }
+
+        Ok(parsed)
+    }
+
+    fn configured_year() -> Result<Year, Box<dyn std::error::Error>> {
+        Year::configured()
+            .ok_or_else(|| "`AOC_YEAR` is not set to a valid year, pass `--year`.".into())
+    }
+
+    /// The time limits of `--timeout` (per part) and `--day-timeout`, in milliseconds.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, Box<dyn std::error::Error>> {
+        Ok(Limits {
+            part: args
+                .opt_value_from_str("--timeout")?
+                .map(Duration::from_millis),
+            day: args
+                .opt_value_from_str("--day-timeout")?
+                .map(Duration::from_millis),
+        })
+    }
+
+    /// The number of days to run at once, passed with `--jobs`.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--jobs")? {
+            Some(0) => Err("`--jobs` expects at least 1 job.".into()),
+            Some(jobs) => Ok(jobs),
+            None => Ok(1),
+        }
+    }
+
+    /// Like [`parse_year`], but `--year all` selects every year that has a solution.
+    fn parse_years(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Vec<Year>, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str::<_, String>("--year")?.as_deref() {
+            Some("all") => Ok(registry::years()),
+            Some(year) => Ok(vec![year.parse()?]),
+            None => Ok(vec![configured_year()?]),
+        }
+    }
+
+    fn finish(
+        args: pico_args::Arguments,
+        app_args: AppArguments,
+    ) -> Result<AppArguments, Box<dyn std::error::Error>> {
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -96,44 +316,71 @@ mod args {
 }
 
 fn main() {
+    #[cfg(not(test))]
+    registry::register(solutions::SOLUTIONS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Accept {
+                year,
+                day,
+                part,
+                answer,
+            } => accept::handle(year, day, part, &answer),
+            AppArguments::All {
+                years,
+                verify,
+                options,
+            } => all::handle(&years, verify, &options),
+            AppArguments::Time {
+                years,
+                day,
+                all,
+                store,
+                memory,
+                options,
+                threshold,
+            } => time::handle(&years, day, all, store, memory, &options, threshold),
+            AppArguments::TimeHistory { year, day } => time::history(year, day),
+            AppArguments::RunDay { year, day, options } => run_day::handle(year, day, &options),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Examples {
+                year,
+                day,
+                overwrite,
+            } => examples::handle(year, day, overwrite),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // download first, so the template can use the puzzle's title and examples.
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
                 }
+                scaffold::handle(year, day, overwrite, &template);
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+            AppArguments::Solve { year, day, options } => solve::handle(year, day, &options),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
+            AppArguments::Today { year } => {
+                match Day::today(year) {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        download::handle(year, day);
+                        scaffold::handle(year, day, false, "default");
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run between December 1 and \
+                            December {}. Please use `scaffold` with a specific day.",
+                            year.day_count()
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,156 @@
+//! Reusable [nom](https://docs.rs/nom) parsers for the common shapes of puzzle inputs.
+//!
+//! ```ignore
+//! use advent_of_code::parse::{comma_separated, lines, parse_all};
+//! use nom::character::complete::i64;
+//!
+//! let points: Vec<Vec<i64>> = parse_all(lines(comma_separated(i64)), "1,2\n3,4\n")?;
+//! ```
+use std::ops::RangeInclusive;
+
+use nom::{
+    IResult, Parser,
+    character::complete::{char, line_ending, one_of},
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+};
+
+pub use crate::template::ParseError;
+
+/// Run `parser` on the whole input. Trailing whitespace is ignored, any other input that is left is an error.
+pub fn parse_all<'a, O>(
+    mut parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+    input: &'a str,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser
+        .parse(input)
+        .map_err(|e| ParseError::from_nom(input, e))?;
+
+    // point at the first character that was not parsed, not at the line break before it.
+    let rest = rest.trim_start();
+
+    if rest.is_empty() {
+        Ok(output)
+    } else {
+        Err(ParseError::at(input, rest, "expected the end of the input"))
+    }
+}
+
+/// One value per line, e.g. `lines(u64)` for a list of numbers. A trailing line break is left to [`parse_all`].
+pub fn lines<'a, O>(
+    parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<&'a str>> {
+    separated_list1(line_ending, parser)
+}
+
+/// Values separated by commas, e.g. `comma_separated(i64)` for `1,-2,3`.
+pub fn comma_separated<'a, O>(
+    parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<&'a str>> {
+    separated_list1(char(','), parser)
+}
+
+/// A range like `3-5`, e.g. `range(u64)`. Both ends are included.
+pub fn range<'a, T>(
+    parser: impl Parser<&'a str, Output = T, Error = Error<&'a str>> + Clone,
+) -> impl Parser<&'a str, Output = RangeInclusive<T>, Error = Error<&'a str>> {
+    separated_pair(parser.clone(), char('-'), parser).map(|(start, end)| start..=end)
+}
+
+/// A non-empty run of characters from `alphabet`, e.g. one line of a table.
+pub fn chars<'a>(
+    alphabet: &'static str,
+) -> impl Parser<&'a str, Output = Vec<char>, Error = Error<&'a str>> {
+    many1(one_of(alphabet))
+}
+
+/// Lines of characters from `alphabet`, e.g. `grid(".#")`. Unlike [`chars`], a character outside of the
+/// alphabet is an error at its position instead of ending the grid.
+pub fn grid<'a>(
+    alphabet: &'static str,
+) -> impl Parser<&'a str, Output = Vec<Vec<char>>, Error = Error<&'a str>> {
+    lines(move |input: &'a str| -> IResult<&'a str, Vec<char>> {
+        let end = input.find(['\r', '\n']).unwrap_or(input.len());
+
+        if end == 0 {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::Many1)));
+        }
+
+        match input[..end].find(|c| !alphabet.contains(c)) {
+            Some(i) => Err(nom::Err::Failure(Error::new(&input[i..], ErrorKind::OneOf))),
+            None => Ok((&input[end..], input[..end].chars().collect())),
+        }
+    })
+}
+
+/// The empty line between two sections of the input.
+pub fn blank_line<'a>() -> impl Parser<&'a str, Output = (), Error = Error<&'a str>> {
+    (line_ending, line_ending).map(|_| ())
+}
+
+/// Sections separated by empty lines, each parsed by `parser`, e.g. `sections(lines(u64))`.
+pub fn sections<'a, O>(
+    parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<&'a str>> {
+    separated_list1(blank_line(), parser)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::character::complete::{i64, u64};
+
+    use super::{blank_line, chars, comma_separated, grid, lines, parse_all, range, sections};
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(parse_all(lines(u64), "1\n2\n3\n"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_all(lines(comma_separated(i64)), "1,-2\r\n3,4"),
+            Ok(vec![vec![1, -2], vec![3, 4]])
+        );
+    }
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(
+            parse_all(lines(range(u64)), "3-5\n10-14\n"),
+            Ok(vec![3..=5, 10..=14])
+        );
+    }
+
+    #[test]
+    fn parses_sections() {
+        assert_eq!(
+            parse_all(sections(lines(u64)), "1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            parse_all((lines(range(u64)), blank_line(), lines(u64)), "1-2\n\n5\n"),
+            Ok((vec![1..=2], (), vec![5]))
+        );
+    }
+
+    #[test]
+    fn parses_grids() {
+        assert_eq!(
+            parse_all(grid(".#"), "#.\n.#\n"),
+            Ok(vec![vec!['#', '.'], vec!['.', '#']])
+        );
+
+        let error = parse_all(grid(".#"), "#.\n.x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn rejects_leftover_input() {
+        let error = parse_all(lines(u64), "1\n2\nthree\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "expected the end of the input");
+
+        let error = parse_all(lines(chars("ab")), "ab\nba c").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
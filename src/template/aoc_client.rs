@@ -0,0 +1,494 @@
+/// Built-in client for the Advent of Code website.
+/// Authenticates with the session cookie from the `AOC_SESSION` variable or a `.adventofcode.session` file.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{Day, Year, data_dir};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    IO(std::io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or create \"~/{SESSION_FILE_NAME}\"."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for AocClientError {
+    fn from(e: std::io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Configures a client for a year from the environment.
+    /// `AOC_BASE_URL` overrides the website address, e.g. to point the client at a mock server.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    /// Fetches the puzzle input for a day.
+    pub fn fetch_input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("/day/{}/input", day.into_inner()))
+    }
+
+    /// Fetches the puzzle description for a day as HTML, stripped to its `<article>` elements.
+    pub fn fetch_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/day/{}", day.into_inner()))?;
+        Ok(extract_articles(&html))
+    }
+
+    /// Posts an answer and interprets the response message.
+    pub fn submit_answer(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionResult, AocClientError> {
+        let response = self
+            .agent
+            .post(&self.url(&format!("/day/{}/answer", day.into_inner())))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        Ok(SubmissionResult::from_response(&html_to_text(
+            &extract_articles(&response),
+        )))
+    }
+
+    pub fn download(&self, day: Day) -> Result<(), AocClientError> {
+        let input_path = get_input_path(self.year, day);
+        let puzzle_path = get_puzzle_path(self.year, day);
+
+        let input = self.fetch_input(day)?;
+        let puzzle = self.fetch_puzzle(day)?;
+        write_file(&input_path, &input)?;
+        write_file(&puzzle_path, &puzzle)?;
+
+        println!("---");
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        );
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            puzzle_path.display()
+        );
+        Ok(())
+    }
+
+    pub fn read(&self, day: Day) -> Result<(), AocClientError> {
+        println!(
+            "Fetching puzzle for day {}, {}...",
+            day.into_inner(),
+            self.year
+        );
+        let puzzle = self.fetch_puzzle(day)?;
+        write_file(&get_puzzle_path(self.year, day), &puzzle)?;
+        println!("\n{}", html_to_text(&puzzle));
+        Ok(())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}{path}", self.base_url, self.year)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+}
+
+pub fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    AocClient::from_env(year)?.read(day)
+}
+
+pub fn download(year: Year, day: Day) -> Result<(), AocClientError> {
+    AocClient::from_env(year)?.download(day)
+}
+
+pub fn submit(
+    year: Year,
+    day: Day,
+    part: u8,
+    result: &str,
+) -> Result<SubmissionResult, AocClientError> {
+    AocClient::from_env(year)?.submit_answer(day, part, result)
+}
+
+fn get_input_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("inputs").join(format!("{day}.txt"))
+}
+
+fn get_puzzle_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("puzzles").join(format!("{day}.md"))
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let home = PathBuf::from(home);
+
+    [
+        home.join(SESSION_FILE_NAME),
+        home.join(".config").join("adventofcode.session"),
+    ]
+    .iter()
+    .find_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .filter(|session| !session.is_empty())
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome of submitting an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubmissionResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited(Duration),
+    AlreadySolved,
+    /// A response that could not be interpreted, holding the message text.
+    Unknown(String),
+}
+
+impl SubmissionResult {
+    /// Interprets the text of a response to an answer submission.
+    pub fn from_response(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            SubmissionResult::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                SubmissionResult::TooHigh
+            } else if text.contains("your answer is too low") {
+                SubmissionResult::TooLow
+            } else {
+                SubmissionResult::Incorrect
+            }
+        } else if text.contains("You gave an answer too recently") {
+            SubmissionResult::RateLimited(parse_wait_time(text).unwrap_or_default())
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmissionResult::AlreadySolved
+        } else {
+            SubmissionResult::Unknown(text.to_string())
+        }
+    }
+
+    /// Whether the server evaluated the answer, i.e. the result says something about its correctness.
+    pub fn is_evaluated(&self) -> bool {
+        matches!(
+            self,
+            SubmissionResult::Correct
+                | SubmissionResult::TooHigh
+                | SubmissionResult::TooLow
+                | SubmissionResult::Incorrect
+        )
+    }
+}
+
+impl Display for SubmissionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionResult::Correct => write!(f, "That's the right answer!"),
+            SubmissionResult::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            SubmissionResult::TooLow => write!(f, "That's not the right answer, it is too low."),
+            SubmissionResult::Incorrect => write!(f, "That's not the right answer."),
+            SubmissionResult::RateLimited(wait) => {
+                write!(f, "Answered too recently, wait {}s.", wait.as_secs())
+            }
+            SubmissionResult::AlreadySolved => write!(f, "This part is already solved."),
+            SubmissionResult::Unknown(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Parses a wait time like `You have 1m 5s left to wait.`
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |total, token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            let secs = match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(secs))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Collects the `<article>` elements of a page, which hold the puzzle text and submission responses.
+fn extract_articles(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + end + "</article>".len();
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    articles.join("\n\n")
+}
+
+/// Renders article HTML as plain text for the terminal.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+
+        match (tag.starts_with('/'), name) {
+            (false, "h2") | (true, "h2" | "p" | "pre" | "ul") => text.push_str("\n\n"),
+            (false, "li") => text.push_str("- "),
+            (true, "li") | (false, "br") => text.push('\n'),
+            _ => {}
+        }
+    }
+
+    text.push_str(&decode_entities(rest));
+
+    text.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .split("\n\n\n")
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        .trim()
+        .to_string()
+}
+
+pub(crate) fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use std::time::Duration;
+
+    use super::{AocClient, SubmissionResult, extract_articles, html_to_text};
+    use crate::{day, year};
+
+    /// Serves a single canned response and forwards the received request.
+    fn mock_server(body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (url, rx) = mock_server("1\n2\n3\n");
+        let client = AocClient::new(&url, "abc\n", year!(2025));
+
+        let input = client.fetch_input(day!(3)).unwrap();
+        let request = rx.recv().unwrap();
+
+        assert_eq!(input, "1\n2\n3\n");
+        assert!(request.starts_with("GET /2025/day/3/input HTTP/1.1"));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn submits_answer_as_form() {
+        let (url, rx) =
+            mock_server("<main><article><p>That's the right answer!</p></article></main>");
+        let client = AocClient::new(&url, "abc", year!(2025));
+
+        let response = client.submit_answer(day!(12), 2, "42").unwrap();
+        let request = rx.recv().unwrap();
+
+        assert_eq!(response, SubmissionResult::Correct);
+        assert!(request.starts_with("POST /2025/day/12/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn interprets_submission_responses() {
+        let cases = [
+            (
+                "That's not the right answer; your answer is too high.",
+                SubmissionResult::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                SubmissionResult::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck...",
+                SubmissionResult::Incorrect,
+            ),
+            (
+                "You gave an answer too recently. You have 1m 6s left to wait.",
+                SubmissionResult::RateLimited(Duration::from_secs(66)),
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                SubmissionResult::AlreadySolved,
+            ),
+            ("Teapot.", SubmissionResult::Unknown("Teapot.".into())),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(SubmissionResult::from_response(text), expected);
+        }
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = "<html><article class=\"day-desc\"><h2>Part 1</h2></article><p>x</p><article><h2>Part 2</h2></article></html>";
+        assert_eq!(
+            extract_articles(html),
+            "<article class=\"day-desc\"><h2>Part 1</h2></article>\n\n<article><h2>Part 2</h2></article>"
+        );
+    }
+
+    #[test]
+    fn renders_text() {
+        let html = "<article><h2>--- Day 1 ---</h2><p>A &lt;b&gt; <em>c</em>.</p><pre><code>1\n2\n</code></pre><ul><li>x</li><li>y</li></ul></article>";
+        assert_eq!(
+            html_to_text(html),
+            "--- Day 1 ---\n\nA <b> c.\n\n1\n2\n\n- x\n- y"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::process;
+
+use crate::template::{Day, Year, ledger::Ledger};
+
+pub fn handle(year: Year, day: Day, part: u8, answer: &str) {
+    if part != 1 && part != 2 {
+        eprintln!("Unexpected part {part}, expecting 1 or 2.");
+        process::exit(1);
+    }
+
+    let mut ledger = Ledger::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read answers: {e}");
+        process::exit(1);
+    });
+    ledger.accept(day, part, answer);
+
+    match ledger.store_file(year) {
+        Ok(()) => println!("Recorded {answer} as the answer for day {day}, part {part}."),
+        Err(e) => {
+            eprintln!("Failed to store answer: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,5 +1,45 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::ledger::Ledger;
+use crate::template::runner::{OutputFormat, RunOptions, reserve_stdout};
+use crate::template::verify::{passes, verify};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Year, all_days, run_multi::run_multi};
+
+pub fn handle(years: &[Year], is_verify: bool, options: &RunOptions) {
+    let format = options.format;
+    let mut is_ok = true;
+
+    if format == OutputFormat::Json {
+        reserve_stdout();
+    }
+
+    for (i, &year) in years.iter().enumerate() {
+        if format == OutputFormat::Text && years.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}\n");
+        }
+
+        let summary = run_multi(year, &all_days(year).collect(), options);
+
+        if !is_verify {
+            continue;
+        }
+
+        let ledger = Ledger::read_from_file(year).unwrap_or_else(|e| {
+            eprintln!("Failed to read answers: {e}");
+            process::exit(1);
+        });
+
+        // keep JSON output machine-readable, the exit code still reports regressions.
+        is_ok &= match format {
+            OutputFormat::Text => verify(&ledger, &summary.answers),
+            OutputFormat::Json => passes(&ledger, &summary.answers),
+        };
+    }
+
+    if !is_ok {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,96 @@
+use std::{fs, process};
+
+use crate::template::examples::{Example, Manifest, extract};
+use crate::template::{Day, Year, data_dir};
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let puzzle_path = data_dir(year).join("puzzles").join(format!("{day}.md"));
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{}\", download the puzzle with `cargo download {day}` first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    };
+
+    let extracted = extract(&puzzle);
+
+    if extracted.inputs.is_empty() {
+        println!("Found no examples in \"{}\".", puzzle_path.display());
+        return;
+    }
+
+    // the answers are assumed to belong to the first example, which is usually the one the text walks through.
+    let answer = |part: usize| extracted.answers.get(part).cloned().flatten();
+
+    let manifest = Manifest {
+        examples: extracted
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(i, _)| Example {
+                name: format!("example-{}", i + 1),
+                part_1: if i == 0 { answer(0) } else { None },
+                part_2: if i == 0 { answer(1) } else { None },
+                ..Example::default()
+            })
+            .collect(),
+    };
+
+    if let Err(e) = fs::create_dir_all(data_dir(year).join("examples")) {
+        eprintln!("Failed to create examples directory: {e}");
+        process::exit(1);
+    }
+
+    for (example, input) in manifest.examples.iter().zip(&extracted.inputs) {
+        let path = example.input_path(year, day);
+
+        if path.exists() && !overwrite {
+            println!("Skipped \"{}\", it already exists.", path.display());
+            continue;
+        }
+
+        match fs::write(&path, input) {
+            Ok(()) => println!(
+                "Wrote {} line(s) to \"{}\"",
+                input.lines().count(),
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    for (part, answer) in [(1, answer(0)), (2, answer(1))] {
+        if let Some(answer) = answer {
+            println!("Part {part} of the first example gives `{answer}`.");
+        }
+    }
+
+    let manifest_path = Manifest::path(year, day);
+
+    if manifest_path.exists() && !overwrite {
+        println!(
+            "Skipped \"{}\", it already exists. Use `--overwrite` to replace it.",
+            manifest_path.display()
+        );
+        return;
+    }
+
+    match manifest.store_file(year, day) {
+        Ok(()) => {
+            println!("Wrote example manifest \"{}\"", manifest_path.display());
+            println!("---");
+            println!(
+                "🎄 Remove the examples that are not puzzle inputs, then type `cargo solve {day} --examples` to check them."
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to write example manifest: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,6 +1,9 @@
+pub mod accept;
 pub mod all;
 pub mod download;
+pub mod examples;
 pub mod read;
+pub mod run_day;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
@@ -0,0 +1,7 @@
+use crate::template::runner::RunOptions;
+use crate::template::{Day, Year, day_process};
+
+/// Run a single day for `all` or `time`, which start the runner with this command to run days that have a time limit.
+pub fn handle(year: Year, day: Day, options: &RunOptions) {
+    day_process::serve(year, day, options);
+}
@@ -1,15 +1,55 @@
 use std::{
-    fs::{File, OpenOptions},
+    env,
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::aoc_client::decode_entities;
+use crate::template::examples::{Manifest, extract};
+use crate::template::{Day, Year, bin_name, data_dir};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// The templates that ship with the template, by name.
+const TEMPLATES: [(&str, &str); 5] = [
+    (
+        "default",
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt")),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "nom",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/nom.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "shared",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/shared.txt"
+        )),
+    ),
+];
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// Directory of user templates if `AOC_TEMPLATE_DIR` is not set.
+const DEFAULT_TEMPLATE_DIR: &str = "templates";
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,18 +59,42 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates a file with the given contents, unless it already exists.
+/// Returns whether the file was created.
+fn create_file_if_missing(path: &Path, contents: &str) -> Result<bool, std::io::Error> {
+    if path.exists() {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(true)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(year: Year, day: Day, overwrite: bool, template: &str) {
+    let data_dir = data_dir(year);
+    let input_path = data_dir.join("inputs").join(format!("{day}.txt"));
+    let example_path = data_dir.join("examples").join(format!("{day}.txt"));
+    let bin_name = bin_name(year, day);
+    let module_path = Path::new("src").join("bin").join(format!("{bin_name}.rs"));
+
+    let module_template = match load_template(template) {
+        Ok(module_template) => module_template,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    // only solutions in year-qualified files declare their year, the others belong to `AOC_YEAR`.
+    let solution_args = if bin_name == day.to_string() {
+        day.into_inner().to_string()
+    } else {
+        format!("{}, year = {year}", day.into_inner())
+    };
+
+    let puzzle = Puzzle::read(year, day);
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,13 +104,24 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let placeholders = Placeholders {
+        solution_args,
+        year,
+        day,
+        title: puzzle.title.clone(),
+        answers: puzzle.answers.clone(),
+    };
+
+    match file.write_all(render(&module_template, &placeholders).as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            if template == "default" {
+                println!("Created module file \"{}\"", module_path.display());
+            } else {
+                println!(
+                    "Created module file \"{}\" from template `{template}`",
+                    module_path.display()
+                );
+            }
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -54,9 +129,12 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    match create_file_if_missing(&input_path, "") {
+        Ok(true) => {
+            println!("Created empty input file \"{}\"", input_path.display());
+        }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -64,9 +142,19 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let example = puzzle.example.as_deref().unwrap_or_default();
+    match create_file_if_missing(&example_path, example) {
+        Ok(true) if example.is_empty() => {
+            println!("Created empty example file \"{}\"", example_path.display());
+        }
+        Ok(true) => {
+            println!(
+                "Created example file \"{}\" from the puzzle",
+                example_path.display()
+            );
+        }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -75,5 +163,183 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if bin_name == day.to_string() {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Directory of user templates, `AOC_TEMPLATE_DIR` or `templates`.
+fn template_dir() -> PathBuf {
+    env::var_os("AOC_TEMPLATE_DIR")
+        .map_or_else(|| PathBuf::from(DEFAULT_TEMPLATE_DIR), PathBuf::from)
+}
+
+/// Loads a template by name. A user template `<name>.txt` takes precedence over a built-in one.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = template_dir().join(format!("{name}.txt"));
+
+    if let Ok(template) = fs::read_to_string(&path) {
+        return Ok(template);
+    }
+
+    TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| (*template).to_string())
+        .ok_or_else(|| {
+            format!(
+                "Unknown template `{name}`. Use one of {} or add \"{}\".",
+                TEMPLATES
+                    .iter()
+                    .map(|(builtin, _)| format!("`{builtin}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                path.display()
+            )
+        })
+}
+
+/// What the downloaded puzzle description of a day tells about it, if it was downloaded.
+#[derive(Debug, Default)]
+struct Puzzle {
+    title: Option<String>,
+    /// The first example input.
+    example: Option<String>,
+    /// The answers the first example gives for part 1 and 2.
+    answers: [Option<String>; 2],
+}
+
+impl Puzzle {
+    fn read(year: Year, day: Day) -> Self {
+        let path = data_dir(year).join("puzzles").join(format!("{day}.md"));
+        let Ok(html) = fs::read_to_string(path) else {
+            return Puzzle::default();
+        };
+
+        let extracted = extract(&html);
+        let mut answers = [
+            extracted.answers.first().cloned().flatten(),
+            extracted.answers.get(1).cloned().flatten(),
+        ];
+
+        // answers in the example manifest were checked by a human, prefer them to guessed ones.
+        if let Some(example) = Manifest::read_from_file(year, day)
+            .ok()
+            .and_then(|manifest| manifest.examples.into_iter().next())
+        {
+            answers = [
+                example.part_1.or(answers[0].take()),
+                example.part_2.or(answers[1].take()),
+            ];
+        }
+
+        Puzzle {
+            title: puzzle_title(&html),
+            example: extracted.inputs.into_iter().next(),
+            answers,
+        }
+    }
+}
+
+/// The title of a puzzle description, e.g. `Day 1: Secret Entrance` for `<h2>--- Day 1: Secret Entrance ---</h2>`.
+fn puzzle_title(html: &str) -> Option<String> {
+    let start = html.find("<h2>")? + "<h2>".len();
+    let end = start + html[start..].find("</h2>")?;
+    let title = decode_entities(&html[start..end]);
+    let title = title.trim().trim_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// The values that replace the placeholders of a template.
+struct Placeholders {
+    solution_args: String,
+    year: Year,
+    day: Day,
+    title: Option<String>,
+    answers: [Option<String>; 2],
+}
+
+/// Replaces the placeholders of a template:
+///
+/// - `%SOLUTION_ARGS%`: the arguments of the `solution!` macro, e.g. `1` or `1, year = 2016`.
+/// - `%DAY%` and `%DAY_NUMBER%`: the day, e.g. `01` and `1`.
+/// - `%YEAR%`: the year, e.g. `2025`.
+/// - `%TITLE%`: the puzzle title, e.g. `Day 1: Secret Entrance`, or `Day 1` if the puzzle was not downloaded.
+/// - `%PART_1_ANSWER%` and `%PART_2_ANSWER%`: the example answer of a part as an `Option<u64>`, e.g. `Some(11)` or `None`.
+fn render(template: &str, placeholders: &Placeholders) -> String {
+    let title = placeholders
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {}", placeholders.day.into_inner()));
+
+    let answer = |part: usize| {
+        placeholders.answers[part]
+            .as_ref()
+            .and_then(|answer| answer.parse::<u64>().ok())
+            .map_or_else(|| "None".to_string(), |answer| format!("Some({answer})"))
+    };
+
+    template
+        .replace("%SOLUTION_ARGS%", &placeholders.solution_args)
+        .replace("%DAY_NUMBER%", &placeholders.day.into_inner().to_string())
+        .replace("%DAY%", &placeholders.day.to_string())
+        .replace("%YEAR%", &placeholders.year.to_string())
+        .replace("%TITLE%", &title)
+        .replace("%PART_1_ANSWER%", &answer(0))
+        .replace("%PART_2_ANSWER%", &answer(1))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Placeholders, TEMPLATES, puzzle_title, render};
+    use crate::{day, year};
+
+    fn placeholders(title: Option<&str>, answers: [Option<&str>; 2]) -> Placeholders {
+        Placeholders {
+            solution_args: "1, year = 2016".into(),
+            year: year!(2016),
+            day: day!(1),
+            title: title.map(String::from),
+            answers: answers.map(|answer| answer.map(String::from)),
+        }
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let template = "//! %TITLE% (%YEAR%, %DAY% / %DAY_NUMBER%)\nsolution!(%SOLUTION_ARGS%);\n%PART_1_ANSWER% %PART_2_ANSWER%";
+
+        assert_eq!(
+            render(
+                template,
+                &placeholders(Some("Day 1: Trebuchet?!"), [Some("142"), Some("abc")])
+            ),
+            "//! Day 1: Trebuchet?! (2016, 01 / 1)\nsolution!(1, year = 2016);\nSome(142) None"
+        );
+        assert_eq!(
+            render("%TITLE%", &placeholders(None, [None, None])),
+            "Day 1"
+        );
+    }
+
+    #[test]
+    fn renders_builtin_templates() {
+        for (name, template) in TEMPLATES {
+            let module = render(template, &placeholders(None, [Some("1"), None]));
+            assert!(
+                !module.contains('%'),
+                "template `{name}` has unknown placeholders"
+            );
+        }
+    }
+
+    #[test]
+    fn reads_puzzle_title() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 7: Camel &amp; Cards ---</h2><p>...</p></article></main>";
+        assert_eq!(puzzle_title(html), Some("Day 7: Camel & Cards".into()));
+        assert_eq!(puzzle_title("<p>no title</p>"), None);
+    }
 }
@@ -1,34 +1,183 @@
-use std::process::{Command, Stdio};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-use crate::template::Day;
+use crate::template::runner::OutputFormat;
+use crate::template::{Day, Year, bin_name, data_dir};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How often watch mode looks for changed files.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
 
-    if dhat {
+/// The flags of `cargo solve`.
+#[derive(Debug, Clone, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    pub dhat: bool,
+    pub submit: Option<u8>,
+    pub format: OutputFormat,
+    pub examples: bool,
+    pub params: Vec<(String, String)>,
+    pub watch: bool,
+}
+
+pub fn handle(year: Year, day: Day, options: &SolveOptions) {
+    if options.watch {
+        watch(year, day, options);
+    }
+
+    // forward a failure, e.g. examples that gave unexpected answers.
+    let status = run(&cargo_args(year, day, options));
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// The arguments of the `cargo run` call that solves a day.
+fn cargo_args(year: Year, day: Day, options: &SolveOptions) -> Vec<String> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), bin_name(year, day)];
+
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+    if options.examples {
+        cmd_args.push("--examples".to_string());
+    }
+
+    for (name, value) in &options.params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(format!("{name}={value}"));
+    }
+
+    if options.format == OutputFormat::Json {
+        cmd_args.push("--format".to_string());
+        cmd_args.push("json".to_string());
+    }
+
+    cmd_args
+}
+
+fn run(cmd_args: &[String]) -> ExitStatus {
+    Command::new("cargo")
+        .args(cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
-        .unwrap();
+        .unwrap()
+        .wait()
+        .unwrap()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Re-run a day whenever its source, examples or input change, until interrupted.
+/// Each run checks the examples first and then solves the input, if there is one.
+fn watch(year: Year, day: Day, options: &SolveOptions) -> ! {
+    // answers are never submitted from watch mode, they would be sent again on every save.
+    let examples_args = cargo_args(
+        year,
+        day,
+        &SolveOptions {
+            submit: None,
+            examples: true,
+            ..options.clone()
+        },
+    );
+    let input_args = cargo_args(
+        year,
+        day,
+        &SolveOptions {
+            submit: None,
+            examples: false,
+            ..options.clone()
+        },
+    );
+
+    let input_path = data_dir(year).join("inputs").join(format!("{day}.txt"));
+    let mut seen = modified_times(&watched_files(year, day));
+
+    loop {
+        // clear the screen and move the cursor to the top left.
+        print!("\x1b[2J\x1b[H");
+        io::stdout().flush().unwrap();
+
+        let passed = run(&examples_args).success();
+
+        if passed && has_content(&input_path) {
+            println!();
+            run(&input_args);
+        }
+
+        println!();
+        println!(
+            "👀 Watching \"src/bin/{}.rs\" and the day's examples and input, press Ctrl-C to stop.",
+            bin_name(year, day)
+        );
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = modified_times(&watched_files(year, day));
+            if current != seen {
+                seen = current;
+                break;
+            }
+        }
+    }
+}
+
+/// The files a change to which re-runs the day. Looked up on every poll, so new example files are picked up.
+fn watched_files(year: Year, day: Day) -> Vec<PathBuf> {
+    let data = data_dir(year);
+    let mut files = vec![
+        PathBuf::from("src/bin").join(format!("{}.rs", bin_name(year, day))),
+        data.join("inputs").join(format!("{day}.txt")),
+    ];
+
+    // the manifest `NN.json` and the inputs `NN.txt` / `NN-name.txt` it refers to.
+    let prefix = day.to_string();
+    if let Ok(entries) = fs::read_dir(data.join("examples")) {
+        let mut examples: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+            })
+            .collect();
+        examples.sort();
+        files.extend(examples);
+    }
+
+    files
+}
+
+fn modified_times(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
 
-    cmd.wait().unwrap();
+fn has_content(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
 }
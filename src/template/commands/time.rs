@@ -1,19 +1,73 @@
 use std::collections::HashSet;
+use std::env;
+use std::process::{self, Command, Stdio};
 
+use crate::template::history::{
+    current_commit, find_memory_regressions, find_regressions, now, print_history,
+    print_memory_regressions, print_regressions,
+};
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{OutputFormat, RunOptions, reserve_stdout};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(
+    years: &[Year],
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    memory: bool,
+    options: &RunOptions,
+    threshold: f64,
+) {
+    // memory is measured by dhat, which needs to be the global allocator of the runner.
+    if memory && !cfg!(feature = "dhat-heap") {
+        rerun_with_dhat();
+    }
+
+    let format = options.format;
+
+    if format == OutputFormat::Json {
+        reserve_stdout();
+    }
+
+    // days that run at the same time compete for the CPU and skew each other's timings.
+    let options = RunOptions {
+        is_timed: true,
+        jobs: 1,
+        ..options.clone()
+    };
+
+    for (i, &year) in years.iter().enumerate() {
+        if format == OutputFormat::Text && years.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}\n");
+        }
+
+        time_year(year, day, run_all, store, &options, threshold);
+    }
+}
+
+fn time_year(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    options: &RunOptions,
+    threshold: f64,
+) {
+    let format = options.format;
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +75,62 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let mut timings = run_multi(year, &days_to_run, options).timings.unwrap();
+
+    let timestamp = now();
+    let commit = current_commit();
+    for timing in &mut timings.data {
+        timing.timestamp = Some(timestamp);
+        timing.commit.clone_from(&commit);
+    }
+
+    if format == OutputFormat::Text {
+        let regressions = find_regressions(&stored_timings, &timings, threshold);
+        print_regressions(&regressions, threshold);
+
+        let regressions = find_memory_regressions(&stored_timings, &timings, threshold);
+        print_memory_regressions(&regressions, threshold);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, &merged_timings) {
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                if format == OutputFormat::Text {
+                    println!("\nStored updated benchmarks.");
+                }
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
 }
+
+/// Run the same `time` command in a build with the `dhat-heap` feature, and exit with its status.
+fn rerun_with_dhat() -> ! {
+    let status = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--profile",
+            "dhat",
+            "--features",
+            "dhat-heap",
+            "--",
+        ])
+        .args(env::args().skip(1))
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .unwrap();
+
+    process::exit(status.code().unwrap_or(1));
+}
+
+/// Print the stored timings of a day, oldest first.
+pub fn history(year: Year, day: Day) {
+    print_history(&Timings::read_from_file(year), day);
+}
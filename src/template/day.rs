@@ -8,7 +8,13 @@ use chrono::{Datelike, FixedOffset, Utc};
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
+use crate::template::Year;
+
+/// The most days an advent of code event has.
+pub const MAX_DAY: u8 = 25;
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Some years have fewer days, see [`Year::day_count`] and [`Day::new_in`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,12 +31,45 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
     }
 
+    /// Creates a [`Day`] from the provided value if the year has that day,
+    /// returns [`None`] otherwise.
+    pub fn new_in(day: u8, year: Year) -> Option<Self> {
+        if day > year.day_count() {
+            return None;
+        }
+        Self::new(day)
+    }
+
+    /// Parse a day of the year's event, e.g. a day passed on the command line.
+    pub fn parse_in(s: &str, year: Year) -> Result<Self, String> {
+        let day: Day = s.parse().map_err(|e: DayFromStrError| e.to_string())?;
+
+        if day > year.day_count() {
+            return Err(format!(
+                "day {} is not part of the {year} event, which has {} days.",
+                day.0,
+                year.day_count()
+            ));
+        }
+
+        Ok(day)
+    }
+
+    /// The day of the year's event on a date in the year, `None` if the event does not run on that date.
+    pub fn on_date(year: Year, month: u32, day: u32) -> Option<Self> {
+        if month == 12 {
+            Self::new_in(u8::try_from(day).ok()?, year)
+        } else {
+            None
+        }
+    }
+
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
         self.0
@@ -39,15 +78,11 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
+    /// Returns the current day if it's a day of the year's event in december, `None` otherwise.
+    pub fn today(year: Year) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        Self::on_date(year, today.month(), today.day())
     }
 }
 
@@ -94,20 +129,23 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of a year's advent, from the 1st to its last day.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of a year's advent, from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -115,10 +153,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -144,10 +182,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2024));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +215,34 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_short_years() {
+        assert_eq!(all_days(year!(2025)).last(), Some(Day(12)));
+        assert_eq!(Day::new_in(12, year!(2025)), Some(Day(12)));
+        assert_eq!(Day::new_in(13, year!(2025)), None);
+        assert_eq!(Day::new_in(13, year!(2024)), Some(Day(13)));
+    }
+
+    #[test]
+    fn follows_configured_day_counts() {
+        // 2019 is not used by any other test, which may run at the same time.
+        let year = year!(2019);
+        year.set_day_count(10);
+
+        assert_eq!(all_days(year).last(), Some(Day(10)));
+
+        assert_eq!(Day::parse_in("10", year), Ok(Day(10)));
+        assert_eq!(
+            Day::parse_in("11", year),
+            Err("day 11 is not part of the 2019 event, which has 10 days.".into())
+        );
+        assert!(Day::parse_in("26", year).is_err());
+
+        assert_eq!(Day::on_date(year, 12, 10), Some(Day(10)));
+        assert_eq!(Day::on_date(year, 12, 11), None);
+        assert_eq!(Day::on_date(year, 11, 10), None);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
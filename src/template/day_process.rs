@@ -0,0 +1,450 @@
+/// Runs a day of `all` and `time` in a child process of the runner, so that a day that exceeds a time limit
+/// can be killed instead of running on in the background, and what the solution prints can be shown with its day.
+/// The child reads the input from stdin and reports its progress on stdout, one [`Event`] per line as JSON.
+use std::{
+    collections::HashMap,
+    env,
+    io::{self, BufRead, BufReader, Write},
+    panic,
+    process::{self, Child, Command, Stdio},
+    str::FromStr,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::runner::{
+    BenchStats, Event, MemoryStats, OutputFormat, PartResult, RunOptions, StageResult, report_to,
+    write_output,
+};
+use crate::template::{Day, Year, registry};
+
+/// The runner's command that runs a single day for its parent, see [`serve`].
+const COMMAND: &str = "run-day";
+
+/// Starts every event on the child's stdout, which it shares with what the solution prints.
+/// An event can follow a line that the solution did not finish, so it is not necessarily at the start of a line.
+const SEPARATOR: char = '\x1e';
+
+/// The command that runs a day with `options` in a child process of the runner.
+pub(crate) fn command(year: Year, day: Day, options: &RunOptions) -> Command {
+    let mut command = Command::new(env::current_exe().expect("failed to locate the runner"));
+
+    let format = match options.format {
+        OutputFormat::Text => "text",
+        OutputFormat::Json => "json",
+    };
+
+    command
+        .args([COMMAND, "--year", &year.to_string(), &day.to_string()])
+        .args(["--format", format])
+        .args(["--warmup", &options.bench.warmup.to_string()])
+        .args([
+            "--min-time",
+            &options.bench.min_time.as_millis().to_string(),
+        ])
+        .args([
+            "--max-time",
+            &options.bench.max_time.as_millis().to_string(),
+        ]);
+
+    if options.is_timed {
+        command.arg("--time");
+    }
+
+    for (name, value) in &options.params {
+        command.args(["--param", &format!("{name}={value}")]);
+    }
+
+    command
+}
+
+/// Start `command` with `input` on its stdin. Returns the child and the events it reports,
+/// the receiver disconnects once the child closes its stdout, e.g. because it exited.
+pub(crate) fn spawn(mut command: Command, input: String) -> io::Result<(Child, Receiver<Event>)> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+
+    let mut stdin = child.stdin.take().expect("stdin of the day is piped");
+    let stdout = child.stdout.take().expect("stdout of the day is piped");
+
+    thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut stdout = BufReader::new(stdout);
+        let mut line = vec![];
+
+        while let Ok(1..) = stdout.read_until(b'\n', &mut line) {
+            for event in parse_line(&String::from_utf8_lossy(&line)) {
+                if sender.send(event).is_err() {
+                    return;
+                }
+            }
+            line.clear();
+        }
+    });
+
+    Ok((child, receiver))
+}
+
+/// The events in a line of the child's stdout. Everything that is not an event was printed by the solution.
+fn parse_line(line: &str) -> Vec<Event> {
+    let Some((printed, event)) = line.split_once(SEPARATOR) else {
+        return vec![Event::Printed(line.into())];
+    };
+
+    let event = JsonValue::from_str(event)
+        .map_err(|e| e.to_string())
+        .and_then(|json| Event::try_from(&json))
+        .unwrap_or_else(|_| Event::Printed(event.into()));
+
+    if printed.is_empty() {
+        vec![event]
+    } else {
+        vec![Event::Printed(printed.into()), event]
+    }
+}
+
+/// Run a registered day for the parent process that started it with [`command`].
+/// What the solution prints goes to stdout along with the events, panics go to stderr.
+pub fn serve(year: Year, day: Day, options: &RunOptions) {
+    let Some(solution) = registry::get(year, day) else {
+        eprintln!("Day {day} of {year} is not solved.");
+        process::exit(1);
+    };
+
+    let input = io::read_to_string(io::stdin()).unwrap_or_else(|e| {
+        eprintln!("Failed to read the input: {e}");
+        process::exit(1);
+    });
+
+    let (sender, receiver) = mpsc::channel();
+
+    let writer = thread::spawn(move || {
+        for event in receiver {
+            let is_finished = matches!(event, Event::Finished { .. });
+            let line = JsonValue::from(&event).stringify().unwrap();
+            write_output(format!("{SEPARATOR}{line}\n").as_bytes());
+
+            if is_finished {
+                break;
+            }
+        }
+    });
+
+    report_to(sender.clone());
+    let panicked = panic::catch_unwind(|| (solution.run)(&input, options)).is_err();
+    let _ = sender.send(Event::Finished { panicked });
+    let _ = writer.join();
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Event> for JsonValue {
+    fn from(value: &Event) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let event = match value {
+            Event::Output(output) => {
+                map.insert("output".into(), JsonValue::String(output.clone()));
+                "output"
+            }
+            Event::Printed(printed) => {
+                map.insert("printed".into(), JsonValue::String(printed.clone()));
+                "printed"
+            }
+            Event::Started => "started",
+            Event::Ran => "ran",
+            Event::Stage(stage, result) => {
+                map.insert("stage".into(), JsonValue::String((*stage).into()));
+                map.insert("result".into(), stage_to_json(result));
+                "stage"
+            }
+            Event::Part(part, result) => {
+                map.insert("part".into(), JsonValue::Number(f64::from(*part)));
+                map.insert(
+                    "result".into(),
+                    result.as_ref().map_or(JsonValue::Null, part_to_json),
+                );
+                "part"
+            }
+            Event::Finished { panicked } => {
+                map.insert("panicked".into(), JsonValue::Boolean(*panicked));
+                "finished"
+            }
+        };
+
+        map.insert("event".into(), JsonValue::String(event.into()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Event {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected event to be a JSON object.")?;
+
+        let field = |key: &str| {
+            json.get(key)
+                .ok_or(format!("Expected event to have key `{key}`."))
+        };
+
+        let event = field("event")?
+            .get::<String>()
+            .ok_or("Expected event.event to be a string.")?;
+
+        match event.as_str() {
+            "output" => field("output")?
+                .get::<String>()
+                .map(|output| Event::Output(output.clone()))
+                .ok_or("Expected event.output to be a string.".into()),
+            "printed" => field("printed")?
+                .get::<String>()
+                .map(|printed| Event::Printed(printed.clone()))
+                .ok_or("Expected event.printed to be a string.".into()),
+            "started" => Ok(Event::Started),
+            "ran" => Ok(Event::Ran),
+            "stage" => {
+                let stage = match field("stage")?.get::<String>().map(String::as_str) {
+                    Some("parse") => "parse",
+                    Some("prepare") => "prepare",
+                    _ => return Err("Expected event.stage to be `parse` or `prepare`.".into()),
+                };
+                Ok(Event::Stage(stage, stage_from_json(field("result")?)?))
+            }
+            "part" => {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let part = field("part")?
+                    .get::<f64>()
+                    .map(|part| *part as u8)
+                    .ok_or("Expected event.part to be a number.")?;
+
+                let result = match field("result")? {
+                    JsonValue::Null => None,
+                    result => Some(part_from_json(result)?),
+                };
+
+                Ok(Event::Part(part, result))
+            }
+            "finished" => field("panicked")?
+                .get::<bool>()
+                .map(|&panicked| Event::Finished { panicked })
+                .ok_or("Expected event.panicked to be a boolean.".into()),
+            event => Err(format!("Unknown event `{event}`.")),
+        }
+    }
+}
+
+/// The duration, samples and statistics that stages and parts have in common.
+#[allow(clippy::cast_precision_loss)]
+fn measurement_to_json(
+    duration: Duration,
+    samples: u128,
+    stats: Option<&BenchStats>,
+    memory: Option<&MemoryStats>,
+) -> HashMap<String, JsonValue> {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert(
+        "duration_nanos".into(),
+        JsonValue::Number(duration.as_nanos() as f64),
+    );
+    map.insert("samples".into(), JsonValue::Number(samples as f64));
+    map.insert(
+        "stats".into(),
+        stats.map_or(JsonValue::Null, JsonValue::from),
+    );
+    map.insert(
+        "memory".into(),
+        memory.map_or(JsonValue::Null, JsonValue::from),
+    );
+
+    map
+}
+
+fn stage_to_json(value: &StageResult) -> JsonValue {
+    JsonValue::Object(measurement_to_json(
+        value.duration,
+        value.samples,
+        value.stats.as_ref(),
+        value.memory.as_ref(),
+    ))
+}
+
+fn part_to_json(value: &PartResult) -> JsonValue {
+    let mut map = measurement_to_json(
+        value.duration,
+        value.samples,
+        value.stats.as_ref(),
+        value.memory.as_ref(),
+    );
+
+    for (key, text) in [("answer", &value.answer), ("error", &value.error)] {
+        map.insert(
+            key.into(),
+            text.clone().map_or(JsonValue::Null, JsonValue::String),
+        );
+    }
+
+    JsonValue::Object(map)
+}
+
+type Measurement = (Duration, u128, Option<BenchStats>, Option<MemoryStats>);
+
+fn measurement_from_json(json: &HashMap<String, JsonValue>) -> Result<Measurement, String> {
+    let number = |key: &str| {
+        json.get(key)
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or(format!("Expected result.{key} to be a number."))
+    };
+
+    let optional = |key: &str| match json.get(key) {
+        None | Some(JsonValue::Null) => None,
+        Some(v) => Some(v),
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok((
+        Duration::from_nanos(number("duration_nanos")? as u64),
+        number("samples")? as u128,
+        optional("stats").map(BenchStats::try_from).transpose()?,
+        optional("memory").map(MemoryStats::try_from).transpose()?,
+    ))
+}
+
+fn stage_from_json(value: &JsonValue) -> Result<StageResult, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected result to be a JSON object.")?;
+
+    let (duration, samples, stats, memory) = measurement_from_json(json)?;
+
+    Ok(StageResult {
+        duration,
+        samples,
+        stats,
+        memory,
+    })
+}
+
+fn part_from_json(value: &JsonValue) -> Result<PartResult, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected result to be a JSON object.")?;
+
+    let (duration, samples, stats, memory) = measurement_from_json(json)?;
+
+    let text = |key: &str| match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::String(text)) => Ok(Some(text.clone())),
+        Some(_) => Err(format!("Expected result.{key} to be null or a string.")),
+    };
+
+    Ok(PartResult {
+        answer: text("answer")?,
+        duration,
+        samples,
+        stats,
+        memory,
+        error: text("error")?,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Event, SEPARATOR, parse_line};
+    use crate::template::runner::{BenchStats, PartResult, StageResult};
+
+    fn round_trip(event: &Event) -> JsonValue {
+        let json = JsonValue::from(event);
+        let line = json.stringify().unwrap();
+        assert!(!line.contains('\n'));
+
+        let parsed = Event::try_from(&line.parse::<JsonValue>().unwrap()).unwrap();
+        assert_eq!(JsonValue::from(&parsed), json);
+        json
+    }
+
+    #[test]
+    fn round_trips_events() {
+        let stats = BenchStats::from_samples(&[Duration::from_micros(3), Duration::from_micros(5)]);
+
+        round_trip(&Event::Output("Part 1: 42\nline two\n".into()));
+        round_trip(&Event::Printed("debug\n".into()));
+        round_trip(&Event::Started);
+        round_trip(&Event::Ran);
+        round_trip(&Event::Stage(
+            "parse",
+            StageResult {
+                duration: Duration::from_micros(4),
+                samples: 2,
+                stats: Some(stats),
+                memory: None,
+            },
+        ));
+        round_trip(&Event::Part(
+            2,
+            Some(PartResult {
+                answer: Some("42".into()),
+                duration: Duration::from_millis(1),
+                samples: 1,
+                stats: None,
+                memory: None,
+                error: None,
+            }),
+        ));
+        round_trip(&Event::Part(1, None));
+        round_trip(&Event::Finished { panicked: true });
+    }
+
+    #[test]
+    fn tells_events_from_printed_text() {
+        let line = |event: &Event| {
+            format!(
+                "{SEPARATOR}{}\n",
+                JsonValue::from(event).stringify().unwrap()
+            )
+        };
+        let json = |events: Vec<Event>| events.iter().map(JsonValue::from).collect::<Vec<_>>();
+
+        assert_eq!(
+            json(parse_line("debug\n")),
+            json(vec![Event::Printed("debug\n".into())])
+        );
+        assert_eq!(json(parse_line(&line(&Event::Ran))), json(vec![Event::Ran]));
+        assert_eq!(
+            json(parse_line(&format!("unfinished{}", line(&Event::Started)))),
+            json(vec![Event::Printed("unfinished".into()), Event::Started])
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_events() {
+        let json: JsonValue = r#"{"event": "exploded"}"#.parse().unwrap();
+        assert!(Event::try_from(&json).is_err());
+
+        let json: JsonValue = r#"{"event": "stage", "stage": "bake", "result": null}"#
+            .parse()
+            .unwrap();
+        assert!(Event::try_from(&json).is_err());
+    }
+}
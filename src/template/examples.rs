@@ -0,0 +1,619 @@
+/// Module that checks solutions against a day's example manifest, e.g. `data/examples/01.json`.
+/// The manifest lists named examples with the answers they are expected to give:
+///
+/// ```json
+/// { "examples": [{ "name": "small", "file": "01.txt", "part_1": 3, "part_2": "6" }] }
+/// ```
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::decode_entities;
+use crate::template::registry::Solution;
+use crate::template::runner::{DayResult, RunOptions};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, data_dir, params};
+
+/// An example input and the answers it is expected to give.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Example {
+    pub name: String,
+    /// The input file in the examples directory, `<day>-<name>.txt` if not set.
+    pub file: Option<String>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    /// Puzzle parameters that differ from the real input, by name.
+    pub params: HashMap<String, String>,
+}
+
+impl Example {
+    pub fn input_path(&self, year: Year, day: Day) -> PathBuf {
+        let file = self
+            .file
+            .clone()
+            .unwrap_or_else(|| format!("{day}-{}.txt", self.name));
+        data_dir(year).join("examples").join(file)
+    }
+
+    fn expected(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            _ => self.part_2.as_ref(),
+        }
+    }
+}
+
+/// The examples of a day.
+/// Can be serialized from JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn path(year: Year, day: Day) -> PathBuf {
+        data_dir(year).join("examples").join(format!("{day}.json"))
+    }
+
+    /// Rehydrate the manifest of a day from its JSON file. If not present, returns an empty manifest.
+    pub fn read_from_file(year: Year, day: Day) -> Result<Self, String> {
+        let path = Self::path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(json) => Manifest::try_from(json).map_err(|e| format!("{}: {e}", path.display())),
+            Err(_) => Ok(Manifest::default()),
+        }
+    }
+
+    /// Dehydrate the manifest of a day to its JSON file.
+    pub fn store_file(&self, year: Year, day: Day) -> Result<(), std::io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(Self::path(year, day))?;
+        json.format_to(&mut file)
+    }
+}
+
+/// A part whose answer differs from the one the manifest expects.
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub example: String,
+    pub part: u8,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "example `{}`, part {}: expected `{}`, got {}",
+            self.example,
+            self.part,
+            self.expected,
+            self.actual
+                .as_ref()
+                .map_or_else(|| "nothing".into(), |a| format!("`{a}`"))
+        )
+    }
+}
+
+/// Compare the answers of a run with the answers an example expects.
+fn compare(example: &Example, result: &DayResult) -> Vec<Mismatch> {
+    result
+        .parts
+        .iter()
+        .filter_map(|(part, result)| {
+            let expected = example.expected(*part)?;
+            let actual = result.as_ref().and_then(|r| r.answer.clone());
+
+            (actual.as_ref() != Some(expected)).then(|| Mismatch {
+                example: example.name.clone(),
+                part: *part,
+                expected: expected.clone(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Run a solution against one example. A panic outside of the parts fails all of the example's parts.
+fn run_example(
+    solution: &Solution,
+    example: &Example,
+    options: &RunOptions,
+) -> Result<Vec<Mismatch>, String> {
+    let path = example.input_path(solution.year, solution.day);
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read example \"{}\": {e}", path.display()))?;
+
+    params::check(solution.params, &example.params)
+        .map_err(|e| format!("example `{}`: {e}", example.name))?;
+
+    let options = RunOptions {
+        params: example.params.clone(),
+        ..options.clone()
+    };
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(&input, &options)))
+        .unwrap_or_else(|_| DayResult {
+            stages: vec![],
+            parts: solution.parts.iter().map(|&part| (part, None)).collect(),
+        });
+
+    Ok(compare(example, &result))
+}
+
+/// Run the examples of a solution, printing the results of every example and the answers that differ.
+/// Returns whether all examples passed.
+pub fn run(solution: &Solution) -> bool {
+    let manifest = match Manifest::read_from_file(solution.year, solution.day) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Failed to read example manifest: {e}");
+            return false;
+        }
+    };
+
+    if manifest.examples.is_empty() {
+        println!(
+            "No examples, add them to \"{}\".",
+            Manifest::path(solution.year, solution.day).display()
+        );
+        return true;
+    }
+
+    let options = RunOptions::default();
+    let mut failed = 0;
+
+    for (i, example) in manifest.examples.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Example {}{ANSI_RESET}", example.name);
+        println!("------");
+
+        match run_example(solution, example, &options) {
+            Ok(mismatches) if mismatches.is_empty() => {}
+            Ok(mismatches) => {
+                failed += 1;
+                for mismatch in mismatches {
+                    println!("✖ part {} expected `{}`", mismatch.part, mismatch.expected);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                println!("✖ {e}");
+            }
+        }
+    }
+
+    let total = manifest.examples.len();
+    if failed == 0 {
+        println!("\n✔ {total} example(s) passed.");
+    } else {
+        println!("\n✖ {failed} of {total} example(s) failed.");
+    }
+
+    failed == 0
+}
+
+/// Check a solution against its examples from a test, failing with the answers that differ.
+pub fn assert_examples(solution: &Solution) {
+    let manifest = Manifest::read_from_file(solution.year, solution.day).unwrap();
+    let options = RunOptions::default();
+
+    let failures: Vec<String> = manifest
+        .examples
+        .iter()
+        .flat_map(|example| match run_example(solution, example, &options) {
+            Ok(mismatches) => mismatches.iter().map(ToString::to_string).collect(),
+            Err(e) => vec![e],
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "examples gave unexpected answers:\n{}",
+        failures.join("\n")
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Phrases that introduce the answer of an example in a puzzle description.
+const ANSWER_PHRASES: [&str; 7] = [
+    "the answer is",
+    "would be",
+    "in total",
+    "a total of",
+    "gives",
+    "produces",
+    "results in",
+];
+
+/// What a puzzle asks for. A sentence about one of these that ends in "is" right before the value,
+/// like "the total calibration value is", introduces the answer as well.
+const RESULT_WORDS: [&str; 4] = [" total", " sum", " product", " answer"];
+
+/// Whether the lowercase sentence before a highlighted value introduces the answer.
+fn introduces_answer(sentence: &str) -> bool {
+    ANSWER_PHRASES
+        .iter()
+        .any(|phrase| sentence.contains(phrase))
+        || (sentence.trim_end().ends_with(" is")
+            && RESULT_WORDS.iter().any(|word| sentence.contains(word)))
+}
+
+/// Example inputs and answers found in a puzzle description.
+#[derive(Debug, Default, PartialEq)]
+pub struct Extracted {
+    /// The content of every `<pre><code>` block, in order.
+    pub inputs: Vec<String>,
+    /// The highlighted answer of each part, for the parts that are in the description.
+    pub answers: Vec<Option<String>>,
+}
+
+/// Find the candidate examples in a puzzle description, as saved by `cargo download`.
+/// Every `<article>` describes one part, and its answer is taken to be the last highlighted
+/// value (`<code><em>`) after a phrase like "gives".
+pub fn extract(html: &str) -> Extracted {
+    let articles: Vec<&str> = html.split("<article").skip(1).collect();
+
+    Extracted {
+        inputs: articles
+            .iter()
+            .flat_map(|article| tag_contents(article, "<pre><code>", "</code></pre>"))
+            .map(|block| decode_entities(&strip_tags(block)))
+            .collect(),
+        answers: articles
+            .iter()
+            .map(|article| find_answer(article))
+            .collect(),
+    }
+}
+
+fn find_answer(article: &str) -> Option<String> {
+    let mut answer = None;
+    let mut offset = 0;
+
+    while let Some(start) = article[offset..].find("<code><em>") {
+        let start = offset + start;
+        let value_start = start + "<code><em>".len();
+        let end = value_start + article[value_start..].find("</em></code>")?;
+
+        let before = strip_tags(&article[..start]).to_lowercase();
+        let sentence = before.rsplit(['.', ':']).next().unwrap_or_default();
+
+        if introduces_answer(sentence) {
+            answer = Some(decode_entities(&strip_tags(&article[value_start..end])));
+        }
+
+        offset = end;
+    }
+
+    answer
+}
+
+fn tag_contents<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut contents = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let Some(end) = rest[start..].find(close) else {
+            break;
+        };
+        contents.push(&rest[start + open.len()..start + end]);
+        rest = &rest[start + end + close.len()..];
+    }
+
+    contents
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Manifest> for JsonValue {
+    fn from(value: &Manifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "examples".into(),
+            JsonValue::Array(value.examples.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&Example> for JsonValue {
+    fn from(value: &Example) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let string_or_null =
+            |s: &Option<String>| s.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert("name".into(), JsonValue::String(value.name.clone()));
+        if let Some(file) = &value.file {
+            map.insert("file".into(), JsonValue::String(file.clone()));
+        }
+        map.insert("part_1".into(), string_or_null(&value.part_1));
+        map.insert("part_2".into(), string_or_null(&value.part_2));
+
+        if !value.params.is_empty() {
+            map.insert(
+                "params".into(),
+                JsonValue::Object(
+                    value
+                        .params
+                        .iter()
+                        .map(|(k, v)| (k.clone(), JsonValue::String(v.clone())))
+                        .collect(),
+                ),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Manifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_examples = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("examples")
+            .ok_or("expected JSON document to have key `examples`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.examples` to be an array.")?;
+
+        Ok(Manifest {
+            examples: json_examples
+                .iter()
+                .map(Example::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Example {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let Some(map) = value.get::<HashMap<String, JsonValue>>() else {
+            return Err("expected example to be an object.".into());
+        };
+
+        let name = map
+            .get("name")
+            .ok_or("expected example to have key `name`.")?
+            .get::<String>()
+            .ok_or("expected `name` to be a string.")?
+            .clone();
+
+        let file = match map.get("file") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::String(file)) => Some(file.clone()),
+            Some(_) => {
+                return Err(format!(
+                    "expected `file` of example `{name}` to be a string."
+                ));
+            }
+        };
+
+        let value = |key: &str| match map.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(value) => value_to_string(value).map(Some).ok_or(format!(
+                "expected `{key}` of example `{name}` to be a string or number."
+            )),
+        };
+
+        let params = match map.get("params") {
+            None | Some(JsonValue::Null) => HashMap::new(),
+            Some(JsonValue::Object(params)) => params
+                .iter()
+                .map(|(key, value)| {
+                    value_to_string(value)
+                        .map(|value| (key.clone(), value))
+                        .ok_or(format!(
+                            "expected param `{key}` of example `{name}` to be a string or number."
+                        ))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => {
+                return Err(format!(
+                    "expected `params` of example `{name}` to be an object."
+                ));
+            }
+        };
+
+        Ok(Example {
+            part_1: value("part_1")?,
+            part_2: value("part_2")?,
+            name,
+            file,
+            params,
+        })
+    }
+}
+
+/// Answers and parameters can be written as strings or numbers. Large numbers should be strings,
+/// as JSON numbers lose precision above 2^53.
+fn value_to_string(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(s) => Some(s.clone()),
+        #[allow(clippy::cast_possible_truncation)]
+        JsonValue::Number(n) if n.fract() == 0.0 => Some((*n as i64).to_string()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Example, Extracted, Manifest, Mismatch, compare, extract};
+    use crate::template::runner::{DayResult, PartResult};
+
+    fn part(part: u8, answer: Option<&str>) -> (u8, Option<PartResult>) {
+        let result = PartResult {
+            answer: answer.map(Into::into),
+            duration: Duration::ZERO,
+            samples: 1,
+            stats: None,
+            memory: None,
+            error: None,
+        };
+        (part, Some(result))
+    }
+
+    #[test]
+    fn reads_manifests() {
+        let json = r#"{ "examples": [
+            { "name": "small", "file": "08.txt", "part_1": 40, "part_2": "25272", "params": { "max": 10 } },
+            { "name": "large", "part_2": null }
+        ] }"#;
+        let manifest = Manifest::try_from(json.to_string()).unwrap();
+
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].file, Some("08.txt".into()));
+        assert_eq!(manifest.examples[0].part_1, Some("40".into()));
+        assert_eq!(manifest.examples[0].part_2, Some("25272".into()));
+        assert_eq!(manifest.examples[0].params["max"], "10");
+        assert_eq!(manifest.examples[1].file, None);
+        assert_eq!(manifest.examples[1].part_1, None);
+        assert_eq!(manifest.examples[1].part_2, None);
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        assert!(Manifest::try_from(r#"{ "data": [] }"#.to_string()).is_err());
+        assert!(Manifest::try_from(r#"{ "examples": [{ "part_1": 1 }] }"#.to_string()).is_err());
+        assert!(
+            Manifest::try_from(r#"{ "examples": [{ "name": "a", "part_1": [] }] }"#.to_string())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn compares_answers() {
+        let example = Example {
+            name: "small".into(),
+            part_1: Some("3".into()),
+            part_2: Some("6".into()),
+            ..Example::default()
+        };
+
+        let result = DayResult {
+            stages: vec![],
+            parts: vec![part(1, Some("3")), part(2, Some("7"))],
+        };
+        assert_eq!(
+            compare(&example, &result),
+            vec![Mismatch {
+                example: "small".into(),
+                part: 2,
+                expected: "6".into(),
+                actual: Some("7".into()),
+            }]
+        );
+
+        let result = DayResult {
+            stages: vec![],
+            parts: vec![part(1, None), (2, None)],
+        };
+        assert_eq!(compare(&example, &result).len(), 2);
+    }
+
+    #[test]
+    fn writes_manifests() {
+        let manifest = Manifest {
+            examples: vec![Example {
+                name: "small".into(),
+                part_1: Some("3".into()),
+                ..Example::default()
+            }],
+        };
+
+        let json = tinyjson::JsonValue::from(&manifest).stringify().unwrap();
+        let manifest = Manifest::try_from(json).unwrap();
+
+        assert_eq!(manifest.examples[0].name, "small");
+        assert_eq!(manifest.examples[0].part_1, Some("3".into()));
+        assert_eq!(manifest.examples[0].part_2, None);
+    }
+
+    #[test]
+    fn extracts_examples_from_puzzles() {
+        let html = r#"<article class="day-desc"><h2>--- Day 1 ---</h2>
+<p>For example:</p>
+<pre><code>1 &lt; 2
+<em>3</em>
+</code></pre>
+<p>There are <code><em>2</em></code> lines. Adding them together gives <code><em>6</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>123</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>4</code></pre>
+<p>Now, the total is <code><em>24</em></code>.</p>
+<p>In this example, <code><em>24</em></code> of them are fresh.</p>
+</article>"#;
+
+        assert_eq!(
+            extract(html),
+            Extracted {
+                inputs: vec!["1 < 2\n3\n".into(), "4".into()],
+                answers: vec![Some("6".into()), Some("24".into())],
+            }
+        );
+    }
+
+    #[test]
+    fn skips_values_that_are_not_answers() {
+        let html = r#"<article class="day-desc"><h2>--- Day 1 ---</h2>
+<p>The first line is <code><em>3</em></code> characters long, and there are <code><em>2</em></code> of them.</p>
+<p>So, in this example, the total calibration value is <code><em>142</em></code>.</p>
+<p>Each of the lines is <code><em>4</em></code> wide, and the sum of their digits is odd.</p>
+</article>"#;
+
+        assert_eq!(extract(html).answers, vec![Some("142".into())]);
+    }
+
+    #[test]
+    fn extracts_nothing_from_plain_text() {
+        assert_eq!(extract("no articles"), Extracted::default());
+        assert_eq!(
+            extract("<article><p>No answer <code><em>here</em></code>.</p></article>"),
+            Extracted {
+                inputs: vec![],
+                answers: vec![None],
+            }
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
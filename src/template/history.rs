@@ -0,0 +1,465 @@
+/// Module that compares benchmark runs with earlier runs of the same day.
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::runner::{BenchStats, MemoryStats, format_bytes};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// A stage or part that got slower than the threshold allows, compared by median.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    pub stage: &'static str,
+    pub previous: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// Change from the previous run, in percent.
+    pub fn change(&self) -> f64 {
+        percent_change(self.previous, self.current)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn percent_change(previous: Duration, current: Duration) -> f64 {
+    let previous = previous.as_nanos() as f64;
+    let current = current.as_nanos() as f64;
+    (current - previous) / previous * 100.0
+}
+
+fn stages(timing: &Timing) -> [(&'static str, Option<&String>, Option<&BenchStats>); 4] {
+    [
+        ("Parse", timing.parse.as_ref(), timing.parse_stats.as_ref()),
+        (
+            "Prepare",
+            timing.prepare.as_ref(),
+            timing.prepare_stats.as_ref(),
+        ),
+        (
+            "Part 1",
+            timing.part_1.as_ref(),
+            timing.part_1_stats.as_ref(),
+        ),
+        (
+            "Part 2",
+            timing.part_2.as_ref(),
+            timing.part_2_stats.as_ref(),
+        ),
+    ]
+}
+
+/// Compare a run with the most recent stored run of each day.
+/// Timings without statistics, e.g. from older versions, are not compared, and neither are the durations of memory runs.
+pub fn find_regressions(stored: &Timings, current: &Timings, threshold: f64) -> Vec<Regression> {
+    let previous = stored.benchmarks().latest();
+    let mut regressions = vec![];
+
+    for timing in current.benchmarks().data.iter() {
+        let Some(previous) = previous.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for ((stage, _, stats), (_, _, previous_stats)) in
+            stages(timing).into_iter().zip(stages(previous))
+        {
+            let (Some(stats), Some(previous_stats)) = (stats, previous_stats) else {
+                continue;
+            };
+
+            if previous_stats.median.is_zero() {
+                continue;
+            }
+
+            let regression = Regression {
+                day: timing.day,
+                stage,
+                previous: previous_stats.median,
+                current: stats.median,
+            };
+
+            if regression.change() > threshold {
+                regressions.push(regression);
+            }
+        }
+    }
+
+    regressions
+}
+
+/// A stage or part whose peak heap usage grew more than the threshold allows.
+#[derive(Debug, PartialEq)]
+pub struct MemoryRegression {
+    pub day: Day,
+    pub stage: &'static str,
+    pub previous: usize,
+    pub current: usize,
+}
+
+impl MemoryRegression {
+    /// Change from the previous run, in percent.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn change(&self) -> f64 {
+        (self.current as f64 - self.previous as f64) / self.previous as f64 * 100.0
+    }
+}
+
+fn memory_stages(timing: &Timing) -> [(&'static str, Option<&MemoryStats>); 4] {
+    [
+        ("Parse", timing.parse_memory.as_ref()),
+        ("Prepare", timing.prepare_memory.as_ref()),
+        ("Part 1", timing.part_1_memory.as_ref()),
+        ("Part 2", timing.part_2_memory.as_ref()),
+    ]
+}
+
+/// Compare the peak heap usage of a run with the most recent stored run of each day that recorded memory.
+pub fn find_memory_regressions(
+    stored: &Timings,
+    current: &Timings,
+    threshold: f64,
+) -> Vec<MemoryRegression> {
+    let mut regressions = vec![];
+
+    for timing in &current.data {
+        let Some(previous) = stored
+            .history(timing.day)
+            .into_iter()
+            .rev()
+            .find(|t| t.peak_memory().is_some())
+        else {
+            continue;
+        };
+
+        for ((stage, memory), (_, previous_memory)) in memory_stages(timing)
+            .into_iter()
+            .zip(memory_stages(previous))
+        {
+            let (Some(memory), Some(previous_memory)) = (memory, previous_memory) else {
+                continue;
+            };
+
+            if previous_memory.peak_bytes == 0 {
+                continue;
+            }
+
+            let regression = MemoryRegression {
+                day: timing.day,
+                stage,
+                previous: previous_memory.peak_bytes,
+                current: memory.peak_bytes,
+            };
+
+            if regression.change() > threshold {
+                regressions.push(regression);
+            }
+        }
+    }
+
+    regressions
+}
+
+pub fn print_regressions(regressions: &[Regression], threshold: f64) {
+    if regressions.is_empty() {
+        return;
+    }
+
+    println!("\n{ANSI_BOLD}Slower than the previous run (> {threshold}%){ANSI_RESET}");
+    for r in regressions {
+        println!(
+            "Day {} {}: {:.1?} -> {:.1?} (+{:.1}%)",
+            r.day,
+            r.stage,
+            r.previous,
+            r.current,
+            r.change()
+        );
+    }
+}
+
+pub fn print_memory_regressions(regressions: &[MemoryRegression], threshold: f64) {
+    if regressions.is_empty() {
+        return;
+    }
+
+    println!("\n{ANSI_BOLD}More memory than the previous run (> {threshold}%){ANSI_RESET}");
+    for r in regressions {
+        println!(
+            "Day {} {}: {} -> {} (+{:.1}%)",
+            r.day,
+            r.stage,
+            format_bytes(r.previous),
+            format_bytes(r.current),
+            r.change()
+        );
+    }
+}
+
+/// Print every stored run of a day, with the change of the median to the run before.
+pub fn print_history(timings: &Timings, day: Day) {
+    let history = timings.history(day);
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    if history.is_empty() {
+        println!("No stored timings.");
+        return;
+    }
+
+    // only show the stages that any of the runs had.
+    let shown: Vec<bool> = (0..4)
+        .map(|i| history.iter().any(|t| stages(t)[i].1.is_some()))
+        .collect();
+
+    let mut titles = vec!["Date", "Commit"];
+    for (i, (title, _, _)) in stages(history[0]).into_iter().enumerate() {
+        if shown[i] {
+            titles.push(title);
+        }
+    }
+
+    println!("| {} |", titles.join(" | "));
+    println!("|{}", " :---: |".repeat(titles.len()));
+
+    let mut previous: Option<&Timing> = None;
+
+    for timing in &history {
+        let mut cells = vec![
+            timing
+                .timestamp
+                .map_or_else(|| "-".into(), format_timestamp),
+            timing.commit.clone().unwrap_or_else(|| "-".into()),
+        ];
+
+        for (i, (_, time, stats)) in stages(timing).into_iter().enumerate() {
+            if !shown[i] {
+                continue;
+            }
+
+            let previous_stats = previous.and_then(|p| stages(p)[i].2);
+            cells.push(format_cell(time, stats, previous_stats));
+        }
+
+        println!("| {} |", cells.join(" | "));
+        previous = Some(timing);
+    }
+}
+
+/// The median if the timing has statistics, with the change to the previous run if it has too.
+fn format_cell(
+    time: Option<&String>,
+    stats: Option<&BenchStats>,
+    previous: Option<&BenchStats>,
+) -> String {
+    match (stats, previous) {
+        (Some(stats), Some(previous)) if !previous.median.is_zero() => format!(
+            "{:.1?} ({:+.1}%)",
+            stats.median,
+            percent_change(previous.median, stats.median)
+        ),
+        (Some(stats), _) => format!("{:.1?}", stats.median),
+        (None, _) => time.cloned().unwrap_or_else(|| "-".into()),
+    }
+}
+
+/// Format a unix timestamp as UTC date and time, e.g. `2025-12-08 10:15`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86400;
+    let seconds = timestamp % 86400;
+
+    // civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    #[allow(clippy::cast_possible_wrap)]
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// The short hash of the checked out git commit, if the working directory is a repository.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        MemoryRegression, Regression, find_memory_regressions, find_regressions, format_timestamp,
+    };
+    use crate::{
+        day,
+        template::{
+            runner::{BenchStats, MemoryStats},
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn get_mock_timing(part_1_micros: u64, part_2_micros: Option<u64>) -> Timing {
+        let stats = |micros| BenchStats::from_samples(&[Duration::from_micros(micros)]);
+
+        Timing {
+            day: day!(1),
+            part_1: Some(format!("{part_1_micros}µs")),
+            part_2: part_2_micros.map(|m| format!("{m}µs")),
+            total_nanos: 0.0,
+            part_1_stats: Some(stats(part_1_micros)),
+            part_2_stats: part_2_micros.map(stats),
+            part_1_status: None,
+            part_2_status: None,
+            parse: None,
+            parse_stats: None,
+            prepare: None,
+            prepare_stats: None,
+            parse_memory: None,
+            prepare_memory: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            timestamp: None,
+            commit: None,
+        }
+    }
+
+    #[test]
+    fn finds_regressions() {
+        let stored = Timings {
+            data: vec![
+                get_mock_timing(200, Some(100)),
+                get_mock_timing(100, Some(100)),
+            ],
+        };
+        let current = Timings {
+            data: vec![get_mock_timing(105, Some(150))],
+        };
+
+        assert_eq!(
+            find_regressions(&stored, &current, 10.0),
+            vec![Regression {
+                day: day!(1),
+                stage: "Part 2",
+                previous: Duration::from_micros(100),
+                current: Duration::from_micros(150),
+            }]
+        );
+        assert_eq!(find_regressions(&stored, &current, 60.0), vec![]);
+    }
+
+    #[test]
+    fn skips_timings_without_stats() {
+        let stored = Timings {
+            data: vec![get_mock_timing(100, None)],
+        };
+        let current = Timings {
+            data: vec![get_mock_timing(100, Some(500))],
+        };
+        assert_eq!(find_regressions(&stored, &current, 10.0), vec![]);
+    }
+
+    #[test]
+    fn skips_memory_runs() {
+        let with_memory = |part_1_micros| Timing {
+            part_1_memory: Some(MemoryStats {
+                total_bytes: 4096,
+                total_blocks: 12,
+                peak_bytes: 1024,
+                peak_blocks: 4,
+            }),
+            ..get_mock_timing(part_1_micros, None)
+        };
+
+        // the profiled run is slower, but neither compared against nor compared with.
+        let stored = Timings {
+            data: vec![get_mock_timing(100, None), with_memory(300)],
+        };
+        assert_eq!(
+            find_regressions(
+                &stored,
+                &Timings {
+                    data: vec![get_mock_timing(105, None)]
+                },
+                10.0
+            ),
+            vec![]
+        );
+        assert_eq!(
+            find_regressions(
+                &stored,
+                &Timings {
+                    data: vec![with_memory(500)]
+                },
+                10.0
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn finds_memory_regressions() {
+        let with_memory = |peak_bytes| Timing {
+            part_1_memory: Some(MemoryStats {
+                total_bytes: 4096,
+                total_blocks: 12,
+                peak_bytes,
+                peak_blocks: 4,
+            }),
+            ..get_mock_timing(100, None)
+        };
+
+        // the latest run without memory is skipped in favor of the one before.
+        let stored = Timings {
+            data: vec![with_memory(1000), get_mock_timing(100, None)],
+        };
+        let current = Timings {
+            data: vec![with_memory(1500)],
+        };
+
+        assert_eq!(
+            find_memory_regressions(&stored, &current, 10.0),
+            vec![MemoryRegression {
+                day: day!(1),
+                stage: "Part 1",
+                previous: 1000,
+                current: 1500,
+            }]
+        );
+        assert_eq!(find_memory_regressions(&stored, &current, 60.0), vec![]);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_765_188_900), "2025-12-08 10:15");
+    }
+}
@@ -0,0 +1,348 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::SubmissionResult;
+use crate::template::{Day, Year, data_dir};
+
+static LEDGER_FILE_NAME: &str = "answers.json";
+
+/// An answer that was evaluated by the server.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub result: SubmissionResult,
+}
+
+/// What the ledger knows about an answer before it is submitted.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// Nothing is known about the answer.
+    Unknown,
+    /// The answer was submitted before, with the given result.
+    Submitted(SubmissionResult),
+    /// The answer is at or above an answer that was too high.
+    AboveTooHigh(String),
+    /// The answer is at or below an answer that was too low.
+    BelowTooLow(String),
+}
+
+/// Record of every evaluated submission, per day and part.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub data: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Dehydrate the ledger to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(data_dir(year).join(LEDGER_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the ledger from a JSON file. If not present, returns an empty ledger.
+    /// A file that can not be read or parsed is an error, so that storing the ledger
+    /// again does not lose the submissions in it.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Ledger::read_from_path(&data_dir(year).join(LEDGER_FILE_NAME))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => Ledger::try_from(json).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Add a submission. Results that say nothing about the answer are ignored.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, result: SubmissionResult) {
+        if !result.is_evaluated() {
+            return;
+        }
+
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.into(),
+            result,
+        });
+    }
+
+    /// Record an answer as accepted by hand, replacing any previously accepted answer.
+    pub fn accept(&mut self, day: Day, part: u8, answer: &str) {
+        self.data
+            .retain(|s| !(s.day == day && s.part == part && s.result == SubmissionResult::Correct));
+        self.record(day, part, answer, SubmissionResult::Correct);
+    }
+
+    /// The accepted answer for a day and part, if known.
+    pub fn accepted(&self, day: Day, part: u8) -> Option<&str> {
+        self.data
+            .iter()
+            .rev()
+            .find(|s| s.day == day && s.part == part && s.result == SubmissionResult::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Check an answer against previous submissions for the same day and part.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Verdict {
+        let submissions = || {
+            self.data
+                .iter()
+                .filter(move |s| s.day == day && s.part == part)
+        };
+
+        if let Some(submission) = submissions().find(|s| s.answer == answer) {
+            return Verdict::Submitted(submission.result.clone());
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Verdict::Unknown;
+        };
+
+        let bound = |result: SubmissionResult| {
+            submissions()
+                .filter(move |s| s.result == result)
+                .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+        };
+
+        if let Some((_, answer)) = bound(SubmissionResult::TooHigh)
+            .filter(|(high, _)| value >= *high)
+            .min()
+        {
+            return Verdict::AboveTooHigh(answer.clone());
+        }
+
+        if let Some((_, answer)) = bound(SubmissionResult::TooLow)
+            .filter(|(low, _)| value <= *low)
+            .max()
+        {
+            return Verdict::BelowTooLow(answer.clone());
+        }
+
+        Verdict::Unknown
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Ledger> for JsonValue {
+    fn from(value: Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Ledger {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn result_to_str(result: &SubmissionResult) -> &'static str {
+    match result {
+        SubmissionResult::Correct => "correct",
+        SubmissionResult::TooHigh => "too_high",
+        SubmissionResult::TooLow => "too_low",
+        _ => "incorrect",
+    }
+}
+
+fn result_from_str(s: &str) -> Option<SubmissionResult> {
+    match s {
+        "correct" => Some(SubmissionResult::Correct),
+        "too_high" => Some(SubmissionResult::TooHigh),
+        "too_low" => Some(SubmissionResult::TooLow),
+        "incorrect" => Some(SubmissionResult::Incorrect),
+        _ => None,
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "result".into(),
+            JsonValue::String(result_to_str(&value.result).into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|part| match part {
+                1.0 => Some(1),
+                2.0 => Some(2),
+                _ => None,
+            })
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let result = json
+            .get("result")
+            .and_then(|v| v.get::<String>())
+            .and_then(|result| result_from_str(result))
+            .ok_or("Expected submission.result to be a known result.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            result,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{Ledger, Verdict};
+    use crate::{day, template::aoc_client::SubmissionResult};
+
+    fn get_mock_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "100", SubmissionResult::TooHigh);
+        ledger.record(day!(1), 1, "10", SubmissionResult::TooLow);
+        ledger.record(day!(1), 1, "50", SubmissionResult::Incorrect);
+        ledger.record(day!(1), 2, "7", SubmissionResult::Correct);
+        ledger
+    }
+
+    #[test]
+    fn ignores_unevaluated_results() {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "1", SubmissionResult::AlreadySolved);
+        ledger.record(day!(1), 1, "1", SubmissionResult::Unknown("?".into()));
+        assert_eq!(ledger.data.len(), 0);
+    }
+
+    #[test]
+    fn finds_known_answers() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(day!(1), 1, "50"),
+            Verdict::Submitted(SubmissionResult::Incorrect)
+        );
+        assert_eq!(
+            ledger.check(day!(1), 2, "7"),
+            Verdict::Submitted(SubmissionResult::Correct)
+        );
+        assert_eq!(ledger.check(day!(2), 1, "50"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(day!(1), 1, "120"),
+            Verdict::AboveTooHigh("100".into())
+        );
+        assert_eq!(
+            ledger.check(day!(1), 1, "3"),
+            Verdict::BelowTooLow("10".into())
+        );
+        assert_eq!(ledger.check(day!(1), 1, "42"), Verdict::Unknown);
+        assert_eq!(ledger.check(day!(1), 1, "abc"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn accepts_answers_by_hand() {
+        let mut ledger = get_mock_ledger();
+        assert_eq!(ledger.accepted(day!(1), 1), None);
+        assert_eq!(ledger.accepted(day!(1), 2), Some("7"));
+
+        ledger.accept(day!(1), 2, "8");
+        assert_eq!(ledger.accepted(day!(1), 2), Some("8"));
+        assert_eq!(ledger.data.len(), 4);
+    }
+
+    #[test]
+    fn reads_missing_and_corrupt_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-ledger-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let ledger = Ledger::read_from_path(&dir.join("missing.json")).unwrap();
+        assert_eq!(ledger.data.len(), 0);
+
+        let corrupt = dir.join("corrupt.json");
+        fs::write(&corrupt, "{\"data\": [").unwrap();
+        let error = Ledger::read_from_path(&corrupt).unwrap_err();
+        assert!(error.starts_with(&corrupt.display().to_string()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let ledger = get_mock_ledger();
+        let json = tinyjson::JsonValue::from(ledger).stringify().unwrap();
+        let parsed = Ledger::try_from(json).unwrap();
+        assert_eq!(parsed.data.len(), 4);
+        assert_eq!(parsed.data[0].answer, "100");
+        assert_eq!(parsed.data[0].result, SubmissionResult::TooHigh);
+        assert_eq!(parsed.data[3].part, 2);
+    }
+}
@@ -1,27 +1,85 @@
-use std::{env, fs};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod params;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
+pub use parse_error::ParseError;
+pub use year::*;
 
 mod day;
+mod day_process;
+mod history;
+mod ledger;
+mod parse_error;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod verify;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Directory of a year's data, `data/<year>`.
+/// The configured year also uses `data` directly as long as `data/<year>` does not exist,
+/// which is the layout of a checkout that only hosts a single year.
+#[must_use]
+pub fn data_dir(year: Year) -> PathBuf {
+    if is_unqualified(year) {
+        PathBuf::from("data")
+    } else {
+        Path::new("data").join(year.to_string())
+    }
+}
+
+/// Name of a day's binary, `<year>-<day>`. Like [`data_dir`], the configured year may leave out the year.
+#[must_use]
+pub fn bin_name(year: Year, day: Day) -> String {
+    if is_unqualified(year) {
+        day.to_string()
+    } else {
+        format!("{year}-{day}")
+    }
+}
+
+fn is_unqualified(year: Year) -> bool {
+    Some(year) == Year::configured() && !Path::new("data").join(year.to_string()).exists()
+}
+
+/// Helper function that reads a text file of the configured year to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    read_file_for(configured_year(), folder, day)
+}
+
+/// Helper function that reads a text file of a year to a string.
+#[must_use]
+pub fn read_file_for(year: Year, folder: &str, day: Day) -> String {
+    try_read_file(year, folder, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning `None` if it can't be read.
+#[must_use]
+pub fn try_read_file(year: Year, folder: &str, day: Day) -> Option<String> {
+    let cwd = env::current_dir().ok()?;
+    let filepath = cwd
+        .join(data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
+    fs::read_to_string(filepath).ok()
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
@@ -29,40 +87,157 @@ pub fn read_file(folder: &str, day: Day) -> String {
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(data_dir(configured_year()))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+fn configured_year() -> Year {
+    Year::configured().expect("`AOC_YEAR` is not set to a valid year")
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// Also creates the constant `SOLUTION`, which describes the day for the solution registry,
+/// and a test that checks the solution against the day's example manifest.
+///
+/// Parts return an `Option` of their answer, `None` while unsolved, or a `Result` whose error is
+/// reported as a failed part, e.g. a [`ParseError`] for malformed input.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The day is followed by optional, comma-separated options:
+///
+/// - `1` or `2` only runs a single part of the solution.
+/// - `year = <year>` declares the solution's year. Without it, the solution belongs to `AOC_YEAR`.
+/// - `parse = <function>` parses the input once before the parts run, and the parts take
+///   a reference to the parsed value instead of the input. Parsing is timed separately.
+/// - `prepare = <function>` does work that both parts need once, after parsing. The function
+///   takes the parsed value (or the input) and the parts take a reference to its output.
+/// - `params = [<name>: <type> = <default>, ...]` declares puzzle parameters with their value for the real
+///   input, and creates a `params` module with an accessor per parameter, e.g. `params::max()`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    (@options $day:expr, $year:tt [$($parse:path)?] [$($prepare:path)?] $params:tt $parts:tt;) => {
+        $crate::solution!(@impl $day, $year [$( ("parse", $parse) )? $( ("prepare", $prepare) )?] $params $parts);
+    };
+    (@options $day:expr, $year:tt $parse:tt $prepare:tt $params:tt $parts:tt; 1 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $year $parse $prepare $params [(part_one, 1)]; $($($rest)*)?);
+    };
+    (@options $day:expr, $year:tt $parse:tt $prepare:tt $params:tt $parts:tt; 2 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $year $parse $prepare $params [(part_two, 2)]; $($($rest)*)?);
+    };
+    (@options $day:expr, $year:tt $parse:tt $prepare:tt $params:tt $parts:tt; year = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, [$value] $parse $prepare $params $parts; $($($rest)*)?);
+    };
+    (@options $day:expr, $year:tt $parse:tt $prepare:tt $params:tt $parts:tt; parse = $value:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $year [$value] $prepare $params $parts; $($($rest)*)?);
+    };
+    (@options $day:expr, $year:tt $parse:tt $prepare:tt $params:tt $parts:tt; prepare = $value:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $year $parse [$value] $params $parts; $($($rest)*)?);
+    };
+    (@options $day:expr, $year:tt $parse:tt $prepare:tt $params:tt $parts:tt; params = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $year $parse $prepare $value $parts; $($($rest)*)?);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+
+    (@year) => {
+        const {
+            match $crate::template::Year::configured() {
+                Some(year) => year,
+                None => panic!("`AOC_YEAR` is not set, set it or declare the solution's year with `year = `"),
+            }
+        }
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@year $year:expr) => {
+        $crate::year!($year)
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr,
+        [$($year:expr)?]
+        [$( ($stage:literal, $stage_func:path) )*]
+        [$( $param:ident : $param_type:ty = $param_default:expr ),* $(,)?]
+        [$( ($func:ident, $part:literal) )*]
+    ) => {
+        /// The current year.
+        #[allow(dead_code)]
+        const YEAR: $crate::template::Year = $crate::solution!(@year $($year)?);
+
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The puzzle parameters of the current run.
+        #[allow(dead_code)]
+        mod params {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                pub fn $param() -> $param_type {
+                    $crate::template::params::get(stringify!($param)).unwrap_or($param_default)
+                }
+            )*
+        }
+
+        /// The current day's solution, for the solution registry.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                year: YEAR,
+                day: DAY,
+                parts: &[$( $part, )*],
+                params: &[$( stringify!($param), )*],
+                run: |input, options| {
+                    use $crate::template::runner::*;
+                    $crate::template::params::with_params(&options.params, || {
+                        let mut result = DayResult::default();
+                        $(
+                            let (output, stage) = measure_stage($stage_func, input, YEAR, DAY, $stage, options);
+                            result.stages.push(($stage, stage));
+                            let input = &output;
+                        )*
+                        $(
+                            result.parts.push(catch_part(YEAR, DAY, $part, options, || {
+                                measure_part($func, input, YEAR, DAY, $part, options)
+                            }));
+                        )*
+                        result
+                    })
+                },
+            };
+
+        /// Checks the solution against the examples in `data/examples/<day>.json`.
+        #[cfg(test)]
+        #[test]
+        fn test_examples() {
+            $crate::template::examples::assert_examples(&SOLUTION);
+        }
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            if std::env::args().any(|arg| arg == "--examples") {
+                let passed = $crate::template::examples::run(&SOLUTION);
+                std::process::exit(if passed { 0 } else { 1 });
+            }
+            let options = RunOptions::from_args();
+            if options.format == OutputFormat::Json {
+                reserve_stdout();
+            }
+            if let Err(e) = $crate::template::params::check(SOLUTION.params, &options.params) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+            let input = $crate::template::read_file_for(YEAR, "inputs", DAY);
+            let input = input.as_str();
+            $crate::template::params::with_params(&options.params, || {
+                $(
+                    let (output, _) = measure_stage($stage_func, input, YEAR, DAY, $stage, &options);
+                    let input = &output;
+                )*
+                $( run_part($func, input, YEAR, DAY, $part); )*
+            });
         }
     };
+
+    ($day:expr $(, $($option:tt)*)?) => {
+        $crate::solution!(@options $day, [] [] [] [] [(part_one, 1) (part_two, 2)]; $($($option)*)?);
+    };
 }
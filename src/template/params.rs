@@ -0,0 +1,129 @@
+/// Puzzle parameters, values that differ between the example and the real input such as a grid size.
+/// A solution declares its parameters and their defaults with `solution!(8, params = [max: usize = 1000])`,
+/// which creates an accessor like `params::max()`. Runs can override the defaults, either with
+/// `--param max=10` or from an example manifest.
+use std::{cell::RefCell, collections::HashMap, fmt::Debug, str::FromStr};
+
+thread_local! {
+    /// The overrides of the run on the current thread.
+    static OVERRIDES: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
+/// Restores the previous overrides when a run ends, even if it panicked.
+struct Restore(HashMap<String, String>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        OVERRIDES.with(|overrides| *overrides.borrow_mut() = std::mem::take(&mut self.0));
+    }
+}
+
+/// Run a function with overridden parameters.
+pub fn with_params<T>(params: &HashMap<String, String>, func: impl FnOnce() -> T) -> T {
+    let previous = OVERRIDES.with(|overrides| overrides.replace(params.clone()));
+    let _restore = Restore(previous);
+    func()
+}
+
+/// The value of a parameter for the current run, `None` if it is not overridden.
+/// Used by the accessors that `solution!` creates.
+pub fn get<T: FromStr>(name: &str) -> Option<T>
+where
+    T::Err: Debug,
+{
+    OVERRIDES.with(|overrides| {
+        let overrides = overrides.borrow();
+        let value = overrides.get(name)?;
+        Some(
+            value.parse().unwrap_or_else(|e| {
+                panic!("invalid value `{value}` for parameter `{name}`: {e:?}")
+            }),
+        )
+    })
+}
+
+/// Check that overrides only name parameters that a solution declares.
+pub fn check(declared: &[&str], params: &HashMap<String, String>) -> Result<(), String> {
+    let mut unknown: Vec<&String> = params
+        .keys()
+        .filter(|name| !declared.contains(&name.as_str()))
+        .collect();
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    unknown.sort();
+    let declared = if declared.is_empty() {
+        "none".into()
+    } else {
+        declared.join(", ")
+    };
+
+    Err(format!(
+        "unknown parameter(s) {}, the solution declares: {declared}.",
+        unknown
+            .iter()
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+/// Parse a `name=value` argument.
+pub fn parse_param(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.into(), value.into())),
+        _ => Err(format!(
+            "expected a parameter like `name=value`, got `{arg}`"
+        )),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{check, get, parse_param, with_params};
+
+    #[test]
+    fn overrides_params_during_a_run() {
+        let params = HashMap::from([("max".to_string(), "10".to_string())]);
+
+        assert_eq!(get::<usize>("max"), None);
+        assert_eq!(with_params(&params, || get::<usize>("max")), Some(10));
+        assert_eq!(get::<usize>("max"), None);
+    }
+
+    #[test]
+    fn restores_params_after_a_panic() {
+        let params = HashMap::from([("max".to_string(), "10".to_string())]);
+
+        let result = std::panic::catch_unwind(|| with_params(&params, || panic!("failed")));
+
+        assert!(result.is_err());
+        assert_eq!(get::<usize>("max"), None);
+    }
+
+    #[test]
+    fn checks_param_names() {
+        let params = HashMap::from([("steps".to_string(), "6".to_string())]);
+
+        assert!(check(&["steps", "size"], &params).is_ok());
+        assert_eq!(
+            check(&["size"], &params),
+            Err("unknown parameter(s) `steps`, the solution declares: size.".into())
+        );
+    }
+
+    #[test]
+    fn parses_params() {
+        assert_eq!(parse_param("max=10"), Ok(("max".into(), "10".into())));
+        assert!(parse_param("max").is_err());
+        assert!(parse_param("=10").is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
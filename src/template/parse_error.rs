@@ -0,0 +1,117 @@
+use std::{error::Error, fmt::Display};
+
+/// An error in a puzzle input, with the position it was found at.
+/// Parts that return a `Result` can use it to report malformed input:
+///
+/// ```text
+/// line 3, column 4: unexpected input (Digit)
+///   3 | 12 x4
+///     |    ^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error in characters, starting at 1.
+    pub column: usize,
+    /// The text of the line the error is on.
+    pub line_text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at `rest`, the remainder of `input` that could not be parsed.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let mut offset = input.len().saturating_sub(rest.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            line_text: input[line_start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Convert the error of a [nom](https://docs.rs/nom) parser that was run on `input`.
+    pub fn from_nom(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) if e.input.is_empty() => ParseError::at(
+                input,
+                e.input,
+                format!("unexpected end of input ({:?})", e.code),
+            ),
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(input, e.input, format!("unexpected input ({:?})", e.code))
+            }
+            nom::Err::Incomplete(_) => ParseError::at(input, "", "unexpected end of input"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {} | {}", self.line, self.line_text)?;
+        write!(f, "  {gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::{
+        Parser,
+        character::complete::{char, digit1},
+    };
+
+    use super::ParseError;
+
+    #[test]
+    fn locates_errors() {
+        let input = "12 34\n56 7x\n";
+        let error = ParseError::at(input, &input[10..], "unexpected `x`");
+
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.line_text, "56 7x");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: unexpected `x`\n  2 | 56 7x\n    |     ^"
+        );
+    }
+
+    #[test]
+    fn converts_nom_errors() {
+        let input = "1,x";
+        let error = (digit1, char(','), digit1).parse(input).unwrap_err();
+
+        let error = ParseError::from_nom(input, error);
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.message, "unexpected input (Digit)");
+
+        let input = "1,";
+        let error = (digit1, char(','), digit1).parse(input).unwrap_err();
+
+        let error = ParseError::from_nom(input, error);
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.message, "unexpected end of input (Digit)");
+    }
+}
@@ -1,9 +1,12 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{collections::HashMap, env, fmt::Display, fs, io, path::Path, str::FromStr};
+use tinyjson::JsonValue;
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::ledger::Ledger;
+use crate::template::runner::{BenchStats, PartStatus, format_bytes, profile_path};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, Year, bin_name, data_dir};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -14,6 +17,15 @@ pub enum Error {
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -26,12 +38,22 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", bin_name(year, day))
+}
+
+/// The marker around a year's table. The year in `data` uses the plain marker, other years add the year,
+/// so that a readme can hold a table for every year.
+fn marker(year: Year) -> String {
+    if data_dir(year) == Path::new("data") {
+        MARKER.into()
+    } else {
+        MARKER.replace(" --->", &format!(" {year} --->"))
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,55 +74,356 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// A column of the benchmark table. The day is always shown first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Parse,
+    Prepare,
+    Part1,
+    Part2,
+    /// Share of the day in the total run time.
+    Percent,
+    /// A star for every part with an accepted answer.
+    Stars,
+    /// Peak heap usage, as recorded by `cargo time --memory` or from the dhat profiles written by `cargo solve --dhat`.
+    Memory,
+}
+
+impl FromStr for Column {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "parse" => Ok(Column::Parse),
+            "prepare" => Ok(Column::Prepare),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "percent" => Ok(Column::Percent),
+            "stars" => Ok(Column::Stars),
+            "memory" => Ok(Column::Memory),
+            _ => Err(Error::Parser(format!("unknown benchmark column `{s}`."))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Day,
+    /// Slowest day first.
+    Time,
+}
+
+/// Which columns the table shows and how its rows are sorted.
+/// Columns without data, e.g. `parse` if no solution declares a parse stage, are left out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableConfig {
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        TableConfig {
+            columns: vec![
+                Column::Parse,
+                Column::Prepare,
+                Column::Part1,
+                Column::Part2,
+                Column::Percent,
+                Column::Stars,
+                Column::Memory,
+            ],
+            sort: SortOrder::Day,
+        }
+    }
+}
+
+impl TableConfig {
+    /// Read the config from the `AOC_BENCHMARK_COLUMNS` and `AOC_BENCHMARK_SORT` environment variables.
+    pub fn from_env() -> Result<Self, Error> {
+        let mut config = TableConfig::default();
+
+        if let Ok(columns) = env::var("AOC_BENCHMARK_COLUMNS") {
+            config.columns = columns
+                .split(',')
+                .map(Column::from_str)
+                .collect::<Result<_, _>>()?;
+        }
+
+        if let Ok(sort) = env::var("AOC_BENCHMARK_SORT") {
+            config.sort = match sort.trim() {
+                "day" => SortOrder::Day,
+                "time" => SortOrder::Time,
+                _ => {
+                    return Err(Error::Parser(format!(
+                        "unknown benchmark sort order `{sort}`, expected `day` or `time`."
+                    )));
+                }
+            };
+        }
+
+        Ok(config)
+    }
+}
+
+/// Data shown in the table besides the timings.
+#[derive(Default)]
+pub struct Extras {
+    pub ledger: Ledger,
+    /// Peak heap usage in bytes, per day.
+    pub memory: HashMap<Day, usize>,
+}
+
+impl Extras {
+    /// The extras of the days in `timings`, with the memory of the latest memory run in `history`.
+    fn read_from_files(year: Year, timings: &Timings, history: &Timings) -> Self {
+        Extras {
+            // the table only reads the ledger, so a broken one costs the stars column, not the update.
+            ledger: Ledger::read_from_file(year).unwrap_or_else(|e| {
+                eprintln!("Failed to read answers, leaving out stars: {e}");
+                Ledger::default()
+            }),
+            memory: timings
+                .data
+                .iter()
+                .filter_map(|t| {
+                    let peak = history
+                        .history(t.day)
+                        .into_iter()
+                        .rev()
+                        .find_map(Timing::peak_memory)
+                        .or_else(|| read_peak_memory(year, t.day))?;
+                    Some((t.day, peak))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The largest heap usage of any profiled stage or part of a day.
+fn read_peak_memory(year: Year, day: Day) -> Option<usize> {
+    ["parse", "prepare", "1", "2"]
+        .iter()
+        .filter_map(|name| fs::read_to_string(profile_path(year, day, name)).ok())
+        .filter_map(|profile| parse_peak_memory(&profile))
+        .max()
+}
+
+/// Sum the bytes that were allocated at the time of the global heap maximum (`t-gmax`).
+fn parse_peak_memory(profile: &str) -> Option<usize> {
+    let json = JsonValue::from_str(profile).ok()?;
+
+    let pps = json
+        .get::<HashMap<String, JsonValue>>()?
+        .get("pps")?
+        .get::<Vec<JsonValue>>()?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bytes = pps
+        .iter()
+        .filter_map(|pp| pp.get::<HashMap<String, JsonValue>>()?.get("gb"))
+        .filter_map(|gb| gb.get::<f64>())
+        .map(|gb| *gb as usize)
+        .sum();
+
+    Some(bytes)
+}
+
+/// Format a part's mean time, followed by median and p95 if the timing has statistics.
+fn format_cell(time: Option<String>, stats: Option<&BenchStats>) -> String {
+    let Some(time) = time else {
+        return "`-`".into();
+    };
+
+    match stats {
+        Some(stats) => format!(
+            "`{time}` <sub>median `{:.1?}`, p95 `{:.1?}`</sub>",
+            stats.median, stats.p95
+        ),
+        None => format!("`{time}`"),
+    }
+}
+
+/// Format a part's cell like [`format_cell`], but name the reason if the part failed, panicked or timed out.
+fn format_part_cell(
+    time: Option<String>,
+    stats: Option<&BenchStats>,
+    status: Option<PartStatus>,
+) -> String {
+    match status {
+        Some(status @ (PartStatus::Failed | PartStatus::Panicked | PartStatus::TimedOut)) => {
+            format!("`✖ {}`", status.label())
+        }
+        _ => format_cell(time, stats),
+    }
+}
+
+fn column_title(column: Column) -> &'static str {
+    match column {
+        Column::Parse => "Parse",
+        Column::Prepare => "Prepare",
+        Column::Part1 => "Part 1",
+        Column::Part2 => "Part 2",
+        Column::Percent => "%",
+        Column::Stars => "Stars",
+        Column::Memory => "Memory",
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    mut timings: Timings,
+    total_millis: f64,
+    config: &TableConfig,
+    extras: &Extras,
+) -> String {
+    let marker = marker(year);
+    let header = if marker == MARKER {
+        format!("{prefix} Benchmarks")
+    } else {
+        format!("{prefix} Benchmarks {year}")
+    };
+
+    // stage and memory columns are only shown if any day has data for them.
+    let columns: Vec<Column> = config
+        .columns
+        .iter()
+        .copied()
+        .filter(|column| match column {
+            Column::Parse => timings.data.iter().any(|t| t.parse.is_some()),
+            Column::Prepare => timings.data.iter().any(|t| t.prepare.is_some()),
+            Column::Memory => !extras.memory.is_empty(),
+            _ => true,
+        })
+        .collect();
+
+    if config.sort == SortOrder::Time {
+        timings
+            .data
+            .sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    }
+
+    let mut titles = vec!["Day"];
+    titles.extend(columns.iter().map(|c| column_title(*c)));
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.clone(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", titles.join(" | ")),
+        format!("|{} :---:  |", " :---: |".repeat(titles.len() - 1)),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let mut cells = vec![format!(
+            "[Day {}]({})",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            get_path_for_bin(year, timing.day)
+        )];
+
+        for column in &columns {
+            cells.push(match column {
+                Column::Parse => format_cell(timing.parse.clone(), timing.parse_stats.as_ref()),
+                Column::Prepare => {
+                    format_cell(timing.prepare.clone(), timing.prepare_stats.as_ref())
+                }
+                Column::Part1 => format_part_cell(
+                    timing.part_1.clone(),
+                    timing.part_1_stats.as_ref(),
+                    timing.part_1_status,
+                ),
+                Column::Part2 => format_part_cell(
+                    timing.part_2.clone(),
+                    timing.part_2_stats.as_ref(),
+                    timing.part_2_status,
+                ),
+                Column::Percent if total_millis > 0.0 => format!(
+                    "{:.1}%",
+                    timing.total_nanos / (total_millis * 1_000_000_f64) * 100.0
+                ),
+                Column::Percent => "-".into(),
+                Column::Stars => [1, 2]
+                    .map(|part| {
+                        if extras.ledger.accepted(timing.day, part).is_some() {
+                            "★"
+                        } else {
+                            "☆"
+                        }
+                    })
+                    .concat(),
+                Column::Memory => extras
+                    .memory
+                    .get(&timing.day)
+                    .map_or_else(|| "-".into(), |bytes| format_bytes(*bytes)),
+            });
+        }
+
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    config: &TableConfig,
+    extras: &Extras,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &marker(year))?;
+    let table = construct_table("##", year, timings, total_millis, config, extras);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Update the table with the latest timing of every day. Memory runs only provide the memory column,
+/// their durations include the overhead of the profiling allocator.
+pub fn update(year: Year, history: &Timings) -> Result<(), Error> {
     let path = "README.md";
+    let config = TableConfig::from_env()?;
+    let timings = history.benchmarks().latest();
+    let extras = Extras::read_from_files(year, &timings, history);
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis, &config, &extras)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::time::Duration;
+
+    use super::{
+        Column, Extras, MARKER, SortOrder, TableConfig, format_cell, format_part_cell,
+        parse_peak_memory, update_content,
+    };
+    use crate::template::{
+        Year,
+        runner::{BenchStats, PartStatus, format_bytes},
+    };
+    use crate::{day, template::timings::Timing, template::timings::Timings, year};
+
+    /// The configured year, whose table uses the plain marker.
+    fn get_year() -> Year {
+        Year::configured().unwrap()
+    }
+
+    /// Only the time columns, as in the original table.
+    fn get_time_config() -> TableConfig {
+        TableConfig {
+            columns: vec![Column::Parse, Column::Prepare, Column::Part1, Column::Part2],
+            sort: SortOrder::Day,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -110,18 +433,60 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 },
             ],
         }
@@ -131,28 +496,68 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +565,15 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -180,4 +593,160 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_stats() {
+        let stats = BenchStats::from_samples(&[
+            Duration::from_micros(10),
+            Duration::from_micros(12),
+            Duration::from_micros(20),
+        ]);
+        assert_eq!(
+            format_cell(Some("14.0µs".into()), Some(&stats)),
+            "`14.0µs` <sub>median `12.0µs`, p95 `20.0µs`</sub>"
+        );
+        assert_eq!(format_cell(None, Some(&stats)), "`-`");
+    }
+
+    #[test]
+    fn formats_failed_parts() {
+        assert_eq!(
+            format_part_cell(None, None, Some(PartStatus::TimedOut)),
+            "`✖ timed out`"
+        );
+        assert_eq!(
+            format_part_cell(Some("1.0µs".into()), None, Some(PartStatus::Failed)),
+            "`✖ failed`"
+        );
+        assert_eq!(
+            format_part_cell(None, None, Some(PartStatus::Unsolved)),
+            "`-`"
+        );
+    }
+
+    #[test]
+    fn adds_parse_column() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_year(),
+            timings,
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
+
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn adds_prepare_column() {
+        let mut timings = get_mock_timings();
+        timings.data[0].parse = Some("1ms".into());
+        timings.data[0].prepare = Some("2ms".into());
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_year(),
+            timings,
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
+
+        assert!(s.contains("| Day | Parse | Prepare | Part 1 | Part 2 |"));
+        assert!(s.contains("| :---: | :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `1ms` | `2ms` | `10ms` | `20ms` |"));
+    }
+
+    #[test]
+    fn adds_extra_columns() {
+        let mut extras = Extras::default();
+        extras.ledger.accept(day!(1), 1, "42");
+        extras.ledger.accept(day!(1), 2, "7");
+        extras.ledger.accept(day!(2), 1, "1");
+        extras.memory.insert(day!(2), 2048);
+
+        let timings = get_mock_timings();
+        let total_millis = timings.total_millis();
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_year(),
+            timings,
+            total_millis,
+            &TableConfig::default(),
+            &extras,
+        )
+        .unwrap();
+
+        assert!(s.contains("| Day | Part 1 | Part 2 | % | Stars | Memory |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | 15.8% | ★★ | - |"));
+        assert!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` | 36.8% | ★☆ | 2.0 KiB |")
+        );
+        assert!(s.contains("| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` | 47.4% | ☆☆ | - |"));
+    }
+
+    #[test]
+    fn sorts_by_time() {
+        let config = TableConfig {
+            columns: vec![Column::Part1],
+            sort: SortOrder::Time,
+        };
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &config,
+            &Extras::default(),
+        )
+        .unwrap();
+
+        let days: Vec<&str> = s
+            .lines()
+            .filter(|l| l.starts_with("| [Day"))
+            .map(|l| &l[3..8])
+            .collect();
+        assert_eq!(days, vec!["Day 4", "Day 2", "Day 1"]);
+    }
+
+    #[test]
+    fn reads_peak_memory() {
+        let profile = r#"{ "dhatFileVersion": 2, "pps": [{ "tb": 100, "gb": 64 }, { "tb": 50, "gb": 36 }, { "tb": 10 }] }"#;
+        assert_eq!(parse_peak_memory(profile), Some(100));
+        assert_eq!(parse_peak_memory("{}"), None);
+        assert_eq!(format_bytes(100), "100 B");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn updates_tables_of_other_years() {
+        let marker = "<!--- benchmarking table 2016 --->";
+        let mut s = format!("{MARKER}{MARKER}\n{marker}{marker}");
+        update_content(
+            &mut s,
+            year!(2016),
+            get_mock_timings(),
+            190.0,
+            &get_time_config(),
+            &Extras::default(),
+        )
+        .unwrap();
+
+        assert!(s.starts_with(&format!("{MARKER}{MARKER}\n{marker}\n## Benchmarks 2016")));
+        assert!(s.contains("| [Day 1](./src/bin/2016-01.rs) | `10ms` | `20ms` |"));
+    }
 }
@@ -0,0 +1,48 @@
+/// Registry of the solutions that are linked into the current process.
+/// The `solution!` macro describes each day as a [`Solution`], and the runner binary registers all of them
+/// so that `all` and `time` can call the solutions directly instead of building a binary per day.
+/// Days with a time limit still run in a child process, which is the runner itself running a single day.
+use std::sync::OnceLock;
+
+use crate::template::runner::{DayResult, RunOptions};
+use crate::template::{Day, Year};
+
+/// Runs a day's stages and parts against an input and prints their results.
+pub type RunFn = fn(&str, &RunOptions) -> DayResult;
+
+/// A day's solution, as declared by the `solution!` macro.
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// The parts that `run` runs.
+    pub parts: &'static [u8],
+    /// The names of the solution's puzzle parameters.
+    pub params: &'static [&'static str],
+    pub run: RunFn,
+}
+
+static SOLUTIONS: OnceLock<&'static [Solution]> = OnceLock::new();
+
+/// Register the solutions available to the current process. Only the first call has an effect.
+pub fn register(solutions: &'static [Solution]) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+/// Look up the registered solution for a day of a year.
+pub fn get(year: Year, day: Day) -> Option<&'static Solution> {
+    SOLUTIONS
+        .get()?
+        .iter()
+        .find(|s| s.year == year && s.day == day)
+}
+
+/// The years that have at least one registered solution, in order.
+pub fn years() -> Vec<Year> {
+    let mut years: Vec<Year> = SOLUTIONS
+        .get()
+        .map(|solutions| solutions.iter().map(|s| s.year).collect())
+        .unwrap_or_default();
+    years.sort();
+    years.dedup();
+    years
+}
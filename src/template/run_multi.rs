@@ -1,257 +1,628 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io::Write,
+    panic,
+    process::Command,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+    },
+    thread,
+    time::Instant,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year, try_read_file};
 
 use super::{
-    all_days,
+    all_days, day_process,
+    registry::{self, Solution},
+    runner::{
+        Event, OutputFormat, PartResult, PartStatus, ResultOutput, RunOptions, StageResult,
+        failure_output, report_to, write_output,
+    },
     timings::{Timing, Timings},
+    verify::Answers,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Days run on a thread of their own, with the stack size of the main thread so that recursive solutions behave the same.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The outcome of running a set of days.
+pub struct RunSummary {
+    /// Benchmark times, present if the run was timed.
+    pub timings: Option<Timings>,
+    pub answers: Vec<Answers>,
+}
+
+/// What a day reported before it finished or was given up on.
+#[derive(Default)]
+struct DayOutcome {
+    stages: Vec<(&'static str, StageResult)>,
+    /// The result of each part, or why it did not finish.
+    parts: Vec<(u8, Result<PartResult, PartStatus>)>,
+}
+
+/// What a day that ran adds to the summary of the run.
+struct DayRun {
+    timing: Timing,
+    answers: Answers,
+    statuses: Vec<(Day, u8, PartStatus)>,
+}
 
-    let mut need_space = false;
+/// Run the registered solutions for a set of days of a year within the current process.
+/// With JSON output, only the records for the parts that ran are printed.
+/// With [`RunOptions::jobs`] above 1, several days run at once and each day's output is printed
+/// in order once the day is done.
+pub fn run_multi(year: Year, days_to_run: &HashSet<Day>, options: &RunOptions) -> RunSummary {
+    let is_text = options.format == OutputFormat::Text;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let runs: Vec<DayRun> = if options.jobs > 1 && days.len() > 1 {
+        run_parallel(year, &days, options)
+    } else {
+        days.iter()
+            .enumerate()
+            .filter_map(|(i, &day)| run_and_report(year, day, i == 0, options, &mut ResultOutput))
+            .collect()
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut timings: Vec<Timing> = Vec::with_capacity(runs.len());
+    let mut answers: Vec<Answers> = Vec::with_capacity(runs.len());
+    let mut statuses: Vec<(Day, u8, PartStatus)> = vec![];
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    for run in runs {
+        timings.push(run.timing);
+        answers.push(run.answers);
+        statuses.extend(run.statuses);
+    }
+
+    if is_text && !statuses.is_empty() {
+        println!("\n{ANSI_BOLD}Summary:{ANSI_RESET} {}", summarize(&statuses));
+    }
 
-    if is_timed {
+    let timings = if options.is_timed {
         let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        if is_text {
+            let total_millis = timings.total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
-    }
-}
+    };
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
+    RunSummary { timings, answers }
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
+/// Run up to [`RunOptions::jobs`] days at once. Each day's output is buffered and printed
+/// as soon as the days before it were printed, so the output reads the same as a serial run.
+fn run_parallel(year: Year, days: &[Day], options: &RunOptions) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(i) else {
+                        break;
+                    };
+
+                    let mut output: Vec<u8> = vec![];
+                    let run = run_and_report(year, day, i == 0, options, &mut output);
+
+                    if sender.send((i, output, run)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+        drop(sender);
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        let mut runs = vec![];
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        for (i, output, run) in receiver {
+            pending.insert(i, (output, run));
+
+            while let Some((output, run)) = pending.remove(&printed) {
+                write_output(&output);
+
+                runs.extend(run);
+                printed += 1;
+            }
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        runs
+    })
+}
 
-        if is_release {
-            args.push("--release");
+/// Run a day and write its output to `out`. Returns `None` for days that did not run,
+/// because they have not been scaffolded yet or have no input.
+fn run_and_report(
+    year: Year,
+    day: Day,
+    is_first: bool,
+    options: &RunOptions,
+    out: &mut dyn Write,
+) -> Option<DayRun> {
+    let is_text = options.format == OutputFormat::Text;
+
+    if is_text {
+        if !is_first {
+            let _ = writeln!(out);
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let _ = writeln!(out, "{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        let _ = writeln!(out, "------");
+    }
+
+    // skip days that have not been scaffolded yet.
+    let Some(solution) = registry::get(year, day) else {
+        if is_text {
+            let _ = writeln!(out, "Not solved.");
         }
+        return None;
+    };
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    let Some(input) = try_read_file(year, "inputs", day) else {
+        if is_text {
+            let _ = writeln!(out, "Missing input file.");
+        }
+        return None;
+    };
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    let mut timing = Timing {
+        day,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        part_1_stats: None,
+        part_2_stats: None,
+        part_1_status: None,
+        part_2_status: None,
+        parse: None,
+        parse_stats: None,
+        prepare: None,
+        prepare_stats: None,
+        parse_memory: None,
+        prepare_memory: None,
+        part_1_memory: None,
+        part_2_memory: None,
+        timestamp: None,
+        commit: None,
+    };
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    let mut answers = Answers {
+        day,
+        part_1: None,
+        part_2: None,
+    };
 
-        let mut output = vec![];
+    let mut statuses = vec![];
+
+    let outcome = run_day(solution, input, options, out);
+
+    for (stage, result) in outcome.stages {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = result.duration.as_nanos() as f64;
+        timing.total_nanos += nanos;
+
+        let (stage_timing, stage_stats, stage_memory) = match stage {
+            "parse" => (
+                &mut timing.parse,
+                &mut timing.parse_stats,
+                &mut timing.parse_memory,
+            ),
+            _ => (
+                &mut timing.prepare,
+                &mut timing.prepare_stats,
+                &mut timing.prepare_memory,
+            ),
+        };
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        *stage_timing = Some(format!("{:.1?}", result.duration));
+        *stage_stats = result.stats;
+        *stage_memory = result.memory;
+    }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    for (part, result) in outcome.parts {
+        let (part_timing, part_stats, part_memory, part_status, part_answer) = match part {
+            1 => (
+                &mut timing.part_1,
+                &mut timing.part_1_stats,
+                &mut timing.part_1_memory,
+                &mut timing.part_1_status,
+                &mut answers.part_1,
+            ),
+            _ => (
+                &mut timing.part_2,
+                &mut timing.part_2_stats,
+                &mut timing.part_2_memory,
+                &mut timing.part_2_status,
+                &mut answers.part_2,
+            ),
+        };
+
+        let result = match result {
+            Ok(result) => result,
+            Err(status) => {
+                *part_status = Some(status);
+                statuses.push((day, part, status));
+                continue;
+            }
+        };
+
+        *part_status = Some(result.status());
+        *part_memory = result.memory;
+        statuses.push((day, part, result.status()));
+
+        if result.answer.is_some() {
+            *part_timing = Some(format!("{:.1?}", result.duration));
+            *part_stats = result.stats;
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = result.duration.as_nanos() as f64;
+            timing.total_nanos += nanos;
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        *part_answer = result.answer;
+    }
 
-        Ok(output)
+    Some(DayRun {
+        timing,
+        answers,
+        statuses,
+    })
+}
+
+/// Run a day and write its output to `out` as it arrives. Without a time limit the day runs on a thread of its own.
+/// With a limit it runs in a child process of the runner instead, which is killed once the day exceeds one of the limits.
+/// Days that run at once also run in a child process, so that what a solution prints is written to `out` with its day.
+fn run_day(
+    solution: &'static Solution,
+    input: String,
+    options: &RunOptions,
+    out: &mut dyn Write,
+) -> DayOutcome {
+    if options.limits.part.is_none() && options.limits.day.is_none() && options.jobs <= 1 {
+        let receiver = run_on_thread(solution, input, options);
+        return receive(solution, &receiver, options, out);
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    let command = day_process::command(solution.year, solution.day, options);
+    run_in_process(solution, command, input, options, out)
+}
+
+/// Run a day on a thread of its own, with the events it reports sent to the returned receiver.
+fn run_on_thread(
+    solution: &'static Solution,
+    input: String,
+    options: &RunOptions,
+) -> Receiver<Event> {
+    let (sender, receiver) = mpsc::channel();
+    let worker_options = options.clone();
+
+    thread::Builder::new()
+        .name(format!("day {}", solution.day))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            report_to(sender.clone());
+            let panicked = panic::catch_unwind(|| (solution.run)(&input, &worker_options)).is_err();
+            let _ = sender.send(Event::Finished { panicked });
+        })
+        .expect("failed to spawn a thread for the day");
+
+    receiver
+}
+
+/// Run a day with `command`, which starts a process that serves it like [`day_process::serve`],
+/// and kill the process once the day finished or was given up on.
+fn run_in_process(
+    solution: &'static Solution,
+    command: Command,
+    input: String,
+    options: &RunOptions,
+    out: &mut dyn Write,
+) -> DayOutcome {
+    let (mut child, receiver) =
+        day_process::spawn(command, input).expect("failed to spawn a process for the day");
+
+    let outcome = receive(solution, &receiver, options, out);
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    outcome
+}
+
+/// Write the output that a day reports to `out` and collect its results, giving up on the day once it exceeds
+/// one of the time limits. The parts that did not report a result by then are failed.
+fn receive(
+    solution: &'static Solution,
+    receiver: &Receiver<Event>,
+    options: &RunOptions,
+    out: &mut dyn Write,
+) -> DayOutcome {
+    let started = Instant::now();
+    let mut step_started = started;
+    let mut is_step_running = false;
+    let mut outcome = DayOutcome::default();
+
+    let failure = loop {
+        let deadline = [
+            options.limits.day.map(|limit| started + limit),
+            options
+                .limits
+                .part
+                .filter(|_| is_step_running)
+                .map(|limit| step_started + limit),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        let event = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
         };
 
-        output
+        match event {
+            Ok(Event::Output(output)) => {
+                let _ = out.write_all(output.as_bytes());
+                let _ = out.flush();
+            }
+            // keep JSON output machine-readable, the records are all that goes to `out` then.
+            Ok(Event::Printed(printed)) if options.format == OutputFormat::Json => {
+                eprint!("{printed}");
+            }
+            Ok(Event::Printed(printed)) => {
+                let _ = out.write_all(printed.as_bytes());
+                let _ = out.flush();
+            }
+            Ok(Event::Started) => {
+                step_started = Instant::now();
+                is_step_running = true;
+            }
+            Ok(Event::Ran) => is_step_running = false,
+            Ok(Event::Stage(stage, result)) => outcome.stages.push((stage, result)),
+            Ok(Event::Part(part, result)) => {
+                outcome
+                    .parts
+                    .push((part, result.ok_or(PartStatus::Panicked)));
+            }
+            Ok(Event::Finished { panicked: false }) => break None,
+            Ok(Event::Finished { panicked: true }) | Err(RecvTimeoutError::Disconnected) => {
+                break Some(PartStatus::Panicked);
+            }
+            Err(RecvTimeoutError::Timeout) => break Some(PartStatus::TimedOut),
+        }
+    };
+
+    // the parts that did not get to report a result share the day's fate.
+    if let Some(status) = failure {
+        for &part in solution.parts {
+            if !outcome.parts.iter().any(|(p, _)| *p == part) {
+                let output = failure_output(solution.year, solution.day, part, status, options);
+                let _ = out.write_all(output.as_bytes());
+                outcome.parts.push((part, Err(status)));
+            }
+        }
+        let _ = out.flush();
+    }
+
+    outcome
+}
+
+/// Count the parts by status, naming the parts that did not produce an answer.
+fn summarize(statuses: &[(Day, u8, PartStatus)]) -> String {
+    [
+        PartStatus::Solved,
+        PartStatus::Unsolved,
+        PartStatus::Failed,
+        PartStatus::Panicked,
+        PartStatus::TimedOut,
+    ]
+    .into_iter()
+    .filter_map(|status| {
+        let parts: Vec<String> = statuses
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            .filter(|(_, _, s)| *s == status)
+            .map(|(day, part, _)| format!("day {day} part {part}"))
+            .collect();
+
+        match (parts.len(), status) {
+            (0, _) => None,
+            (n, PartStatus::Solved) => Some(format!("{n} solved")),
+            (n, _) => Some(format!("{n} {} ({})", status.label(), parts.join(", "))),
+        }
+    })
+    .collect::<Vec<_>>()
+    .join(", ")
+}
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+/* -------------------------------------------------------------------------- */
 
-                timings.total_nanos += nanos;
-            });
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, io, process, process::Command, thread, time::Duration};
 
-        timings
-    }
+    use tinyjson::JsonValue;
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    use super::{run_day, run_in_process, summarize};
+    use crate::template::registry::Solution;
+    use crate::template::runner::{
+        DayResult, Event, Limits, OutputFormat, PartResult, PartStatus, RunOptions, measure_stage,
+    };
+    use crate::{day, year};
+
+    /// A day whose run is played by the command that a test passes to `run_in_process`.
+    static RUNS_IN_A_SCRIPT: Solution = Solution {
+        year: year!(2024),
+        day: day!(1),
+        parts: &[1, 2],
+        params: &[],
+        run: |_, _| DayResult::default(),
+    };
+
+    static PANICS_WHILE_PARSING: Solution = Solution {
+        year: year!(2024),
+        day: day!(2),
+        parts: &[1, 2],
+        params: &[],
+        run: |input, options| {
+            measure_stage(
+                |_: &str| -> u32 { panic!("bad input") },
+                input,
+                year!(2024),
+                day!(2),
+                "parse",
+                options,
+            );
+            DayResult::default()
+        },
+    };
+
+    fn statuses(outcome: &super::DayOutcome) -> Vec<(u8, Option<PartStatus>)> {
+        outcome
+            .parts
+            .iter()
+            .map(|(part, result)| (*part, result.as_ref().err().copied()))
+            .collect()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[cfg(unix)]
+    #[test]
+    fn kills_days_that_time_out() {
+        let options = RunOptions {
+            limits: Limits {
+                part: Some(Duration::from_millis(50)),
+                day: None,
+            },
+            ..RunOptions::default()
+        };
+
+        let solved = PartResult {
+            answer: Some("1".into()),
+            duration: Duration::from_millis(1),
+            samples: 1,
+            stats: None,
+            memory: None,
+            error: None,
+        };
+        let event = |event: &Event| JsonValue::from(event).stringify().unwrap();
+
+        // solves part one, then hangs in part two. Touches `marker` if it is not stopped.
+        let marker = env::temp_dir().join(format!("aoc-hung-day-{}", process::id()));
+        let mut command = Command::new("sh");
+        command.args([
+            "-c",
+            r#"cat > /dev/null; printf '\036%s\n' "$1" "$2"; sleep 1; touch "$3""#,
+            "sh",
+            &event(&Event::Part(1, Some(solved))),
+            &event(&Event::Started),
+            &marker.to_string_lossy(),
+        ]);
+
+        let outcome = run_in_process(
+            &RUNS_IN_A_SCRIPT,
+            command,
+            String::new(),
+            &options,
+            &mut io::sink(),
+        );
+
+        assert_eq!(
+            statuses(&outcome),
+            vec![(1, None), (2, Some(PartStatus::TimedOut))]
+        );
+
+        thread::sleep(Duration::from_millis(1500));
+        assert!(!marker.exists(), "the day kept running after it timed out");
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
+    #[cfg(unix)]
+    #[test]
+    fn writes_what_solutions_print_with_their_day() {
+        let event = |event: &Event| JsonValue::from(event).stringify().unwrap();
+
+        // prints a line and an unfinished one around the output of part one.
+        let run = |format| {
+            let mut command = Command::new("sh");
+            command.args([
+                "-c",
+                r#"cat > /dev/null; printf 'debug\n'; printf '\036%s\nunfinished' "$1"; printf '\036%s\n' "$2""#,
+                "sh",
+                &event(&Event::Output("Part 1: 1\n".into())),
+                &event(&Event::Finished { panicked: false }),
+            ]);
+
+            let mut out = vec![];
+            let options = RunOptions {
+                format,
+                ..RunOptions::default()
+            };
+            run_in_process(
+                &RUNS_IN_A_SCRIPT,
+                command,
+                String::new(),
+                &options,
+                &mut out,
             );
-        }};
+            String::from_utf8(out).unwrap()
+        };
+
+        assert_eq!(run(OutputFormat::Text), "debug\nPart 1: 1\nunfinished");
+        assert_eq!(run(OutputFormat::Json), "Part 1: 1\n");
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn fails_all_parts_if_a_stage_panics() {
+        let outcome = run_day(
+            &PANICS_WHILE_PARSING,
+            String::new(),
+            &RunOptions::default(),
+            &mut io::sink(),
+        );
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+        assert_eq!(
+            statuses(&outcome),
+            vec![
+                (1, Some(PartStatus::Panicked)),
+                (2, Some(PartStatus::Panicked))
+            ]
+        );
+    }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn summarizes_statuses() {
+        let statuses = [
+            (day!(1), 1, PartStatus::Solved),
+            (day!(1), 2, PartStatus::Solved),
+            (day!(3), 1, PartStatus::Failed),
+            (day!(10), 2, PartStatus::Panicked),
+            (day!(12), 1, PartStatus::TimedOut),
+            (day!(12), 2, PartStatus::TimedOut),
+        ];
+
+        assert_eq!(
+            summarize(&statuses),
+            "2 solved, 1 failed (day 03 part 1), 1 panicked (day 10 part 2), 2 timed out (day 12 part 1, day 12 part 2)"
+        );
     }
 }
+
+/* -------------------------------------------------------------------------- */
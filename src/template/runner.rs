@@ -1,142 +1,994 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::fs::File;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::sync::mpsc::Sender;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
+
+use tinyjson::JsonValue;
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::aoc_client::SubmissionResult;
+use crate::template::ledger::{Ledger, Verdict};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, Year, aoc_client, data_dir, params};
+
+/// How the runner reports results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable lines, as printed by default.
+    #[default]
+    Text,
+    /// One JSON object per part and line (JSON Lines).
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "unknown output format `{s}`, expected `text` or `json`"
+            )),
+        }
+    }
+}
+
+/// Options that control how solution parts are run and reported.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    pub format: OutputFormat,
+    pub bench: BenchOptions,
+    /// Overrides of the solution's puzzle parameters, by name.
+    pub params: HashMap<String, String>,
+    pub limits: Limits,
+    /// How many days `all` runs at once. Days run one after another unless this is above 1, `time` always does.
+    pub jobs: usize,
+}
+
+impl RunOptions {
+    /// Read the options from the arguments a solution binary was called with.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let value_of = |flag: &str| {
+            args.iter()
+                .position(|x| x == flag)
+                .and_then(|i| args.get(i + 1))
+        };
+
+        let format = value_of("--format")
+            .map_or(Ok(OutputFormat::Text), |f| f.parse())
+            .unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            });
+
+        let parse_or_exit = |flag: &str| {
+            value_of(flag).map(|v| {
+                v.parse::<u64>().unwrap_or_else(|_| {
+                    eprintln!("Expected a number for `{flag}`, got `{v}`.");
+                    process::exit(1);
+                })
+            })
+        };
+
+        let params = args
+            .windows(2)
+            .filter(|w| w[0] == "--param")
+            .map(|w| params::parse_param(&w[1]))
+            .collect::<Result<_, _>>()
+            .unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            });
+
+        let defaults = BenchOptions::default();
+
+        RunOptions {
+            is_timed: args.iter().any(|x| x == "--time"),
+            format,
+            bench: BenchOptions {
+                #[allow(clippy::cast_possible_truncation)]
+                warmup: parse_or_exit("--warmup").map_or(defaults.warmup, |n| n as u32),
+                min_time: parse_or_exit("--min-time")
+                    .map_or(defaults.min_time, Duration::from_millis),
+                max_time: parse_or_exit("--max-time")
+                    .map_or(defaults.max_time, Duration::from_millis),
+            },
+            params,
+            limits: Limits::default(),
+            jobs: 1,
+        }
+    }
+}
+
+/// Controls how long a part is benched for.
+#[derive(Clone, Debug)]
+pub struct BenchOptions {
+    /// Iterations that are run, but not measured, before sampling starts.
+    pub warmup: u32,
+    /// Keep sampling until at least this much time was spent measuring.
+    pub min_time: Duration,
+    /// Stop sampling once this much time was spent measuring, even if fewer than the minimum samples were taken.
+    pub max_time: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            min_time: Duration::from_secs(1),
+            max_time: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Time limits for the days that `all` and `time` run, see [`crate::template::run_multi`].
+/// Runs without a limit by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Limits {
+    /// Limit for the first run of a single part or stage. Benching is bounded by [`BenchOptions::max_time`] instead.
+    pub part: Option<Duration>,
+    /// Limit for everything a day runs, including benching.
+    pub day: Option<Duration>,
+}
+
+const MIN_SAMPLES: u128 = 10;
+const MAX_SAMPLES: u128 = 10000;
+
+/// Summary statistics of the samples taken while benching a part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+}
+
+impl BenchStats {
+    /// Compute the statistics for a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        #[allow(clippy::cast_precision_loss)]
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        // nearest-rank percentile.
+        let p95 = sorted[(n * 95).div_ceil(100).saturating_sub(1)];
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let from_nanos = |x: f64| Duration::from_nanos(x.round() as u64);
+
+        BenchStats {
+            samples: n as u128,
+            mean: from_nanos(mean),
+            median,
+            min: sorted[0],
+            max: sorted[n - 1],
+            std_dev: from_nanos(variance.sqrt()),
+            p95,
+        }
+    }
+}
+
+/// Heap usage of the first run of a part or stage, measured by dhat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Bytes allocated over the run, whether they were freed or not.
+    pub total_bytes: u64,
+    /// Number of allocations over the run.
+    pub total_blocks: u64,
+    /// Bytes allocated at the peak of the run's heap usage.
+    pub peak_bytes: usize,
+    pub peak_blocks: usize,
+}
+
+#[cfg(feature = "dhat-heap")]
+impl From<dhat::HeapStats> for MemoryStats {
+    fn from(stats: dhat::HeapStats) -> Self {
+        MemoryStats {
+            total_bytes: stats.total_bytes,
+            total_blocks: stats.total_blocks,
+            peak_bytes: stats.max_bytes,
+            peak_blocks: stats.max_blocks,
+        }
+    }
+}
+
+/// The outcome of running a single part of a solution.
+#[derive(Clone)]
+pub struct PartResult {
+    pub answer: Option<String>,
+    /// Mean duration when benched, the duration of the single run otherwise.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Present if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// Present if the runner was built with the `dhat-heap` feature.
+    pub memory: Option<MemoryStats>,
+    /// Why the part failed, if it returned an error.
+    pub error: Option<String>,
+}
+
+/// Whether a part produced an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+    /// The part returned an error, e.g. because its input was malformed.
+    Failed,
+    Panicked,
+    /// The part exceeded a time limit, or its day did before the part got to finish.
+    TimedOut,
+}
+
+impl PartStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+            PartStatus::Panicked => "panicked",
+            PartStatus::TimedOut => "timed_out",
+        }
+    }
+
+    /// The status as shown in text output.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            PartStatus::TimedOut => "timed out",
+            status => status.as_str(),
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            PartStatus::Solved,
+            PartStatus::Unsolved,
+            PartStatus::Failed,
+            PartStatus::Panicked,
+            PartStatus::TimedOut,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == s)
+        .ok_or(format!("unknown part status `{s}`"))
+    }
+}
+
+impl PartResult {
+    pub fn status(&self) -> PartStatus {
+        if self.answer.is_some() {
+            PartStatus::Solved
+        } else if self.error.is_some() {
+            PartStatus::Failed
+        } else {
+            PartStatus::Unsolved
+        }
+    }
+}
+
+/// What a part can return: an `Option`, which is `None` while the part is unsolved,
+/// or a `Result`, whose error is reported if the part failed, e.g. on malformed input.
+pub trait PartOutput {
+    /// The answer, `Ok(None)` if the part is unsolved and `Err` with the error if it failed.
+    fn answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: PartOutput, E: Display> PartOutput for Result<T, E> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(output) => output.answer(),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Answers that a part can return directly or as the value of a `Result`, e.g. `Result<u64, ParseError>`.
+macro_rules! answer_types {
+    ($($t:ty),*) => {
+        $(
+            impl PartOutput for $t {
+                fn answer(&self) -> Result<Option<String>, String> {
+                    Ok(Some(self.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+answer_types!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String
+);
+
+pub fn run_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    let options = RunOptions::from_args();
+    let (result, part_result) = run_reported(func, input, year, day, part, &options);
+
+    if options.format == OutputFormat::Json {
+        print_record(year, day, part, &part_result, part_result.status());
+    }
+
+    if let Ok(Some(answer)) = result {
+        submit_result(answer, year, day, part);
+    }
+}
+
+/// Run a solution part from within another process, e.g. the `all` command.
+/// Prints the same output as [`run_part`], but returns the result instead of submitting it.
+pub fn measure_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    let (_, part_result) = run_reported(func, input, year, day, part, options);
+
+    if options.format == OutputFormat::Json {
+        print_record(year, day, part, &part_result, part_result.status());
+    }
+
+    part_result
+}
+
+/// The outcome of running a stage that prepares the input for the parts, e.g. parsing.
+#[derive(Clone)]
+pub struct StageResult {
+    pub duration: Duration,
+    pub samples: u128,
+    /// Present if the stage was benched.
+    pub stats: Option<BenchStats>,
+    /// Present if the runner was built with the `dhat-heap` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+/// Everything a day's solution reported when it was run through the registry.
+#[derive(Default)]
+pub struct DayResult {
+    pub stages: Vec<(&'static str, StageResult)>,
+    /// The result of each part, `None` if the part panicked.
+    pub parts: Vec<(u8, Option<PartResult>)>,
+}
+
+/// Run a stage such as `parse` and print its duration. Returns the stage's output for the parts.
+pub fn measure_stage<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    year: Year,
+    day: Day,
+    stage: &'static str,
+    options: &RunOptions,
+) -> (T, StageResult) {
+    let label = stage_label(stage);
+    let is_text = options.format == OutputFormat::Text;
+
+    report(Event::Started);
+
+    let (output, base_time, stats, memory) = run_timed(
+        func,
+        input,
+        |_| {
+            if is_text {
+                print_stage(&label, "");
+            }
+        },
+        &profile_path(year, day, stage),
+        options,
+    );
+
+    let stage_result = StageResult {
+        duration: stats.as_ref().map_or(base_time, |s| s.mean),
+        samples: stats.as_ref().map_or(1, |s| s.samples),
+        stats,
+        memory,
+    };
+
+    match options.format {
+        OutputFormat::Text => print_stage(
+            &label,
+            &format!(
+                "{}{}",
+                format_duration(stage_result.duration, stage_result.stats.as_ref()),
+                format_memory(stage_result.memory.as_ref())
+            ),
+        ),
+        OutputFormat::Json => print_json(stage_record(year, day, stage, &stage_result)),
+    }
+
+    report(Event::Stage(stage, stage_result.clone()));
+
+    (output, stage_result)
+}
+
+/// Run a part for the registry, treating a panic like an unsolved part so that the remaining parts still run.
+pub fn catch_part(
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+    func: impl FnOnce() -> PartResult,
+) -> (u8, Option<PartResult>) {
+    report(Event::Started);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+
+    if result.is_err() {
+        report_failure(year, day, part, PartStatus::Panicked, options);
+    }
+
+    report(Event::Part(part, result.as_ref().ok().cloned()));
+
+    (part, result.ok())
+}
+
+/// Report a part that did not finish, because it panicked or timed out.
+pub(crate) fn report_failure(
+    year: Year,
+    day: Day,
+    part: u8,
+    status: PartStatus,
+    options: &RunOptions,
+) {
+    emit(failure_output(year, day, part, status, options));
+}
+
+/// The output that reports a part that did not finish, see [`report_failure`].
+pub(crate) fn failure_output(
+    year: Year,
+    day: Day,
+    part: u8,
+    status: PartStatus,
+    options: &RunOptions,
+) -> String {
+    match options.format {
+        OutputFormat::Text => format!(
+            "\rPart {part}: ✖ {ANSI_ITALIC}{}{ANSI_RESET}             \n",
+            status.label()
+        ),
+        OutputFormat::Json => {
+            let result = PartResult {
+                answer: None,
+                duration: Duration::ZERO,
+                samples: 0,
+                stats: None,
+                memory: None,
+                error: None,
+            };
+            json_line(&part_record(year, day, part, &result, status))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Progress of the runs on a thread that reports to [`report_to`].
+pub(crate) enum Event {
+    /// Text that the run would otherwise print to stdout.
+    Output(String),
+    /// Text that a solution printed itself. Only days that run in a child process report it.
+    Printed(String),
+    /// A part or stage started its first run.
+    Started,
+    /// The first run of the current part or stage finished, it may still be benched.
+    Ran,
+    Stage(&'static str, StageResult),
+    /// A part finished, `None` if it panicked.
+    Part(u8, Option<PartResult>),
+    /// The day's run ended. A panic outside of the parts, e.g. while parsing, fails the parts that did not finish.
+    Finished {
+        panicked: bool,
+    },
+}
+
+thread_local! {
+    /// Where the runs on the current thread report their progress, if not to stdout.
+    static REPORTER: RefCell<Option<Sender<Event>>> = const { RefCell::new(None) };
+}
+
+/// Send the progress and output of the runs on the current thread to `sender`, instead of printing it.
+/// Once the receiver is gone, e.g. after the run timed out, the run's output is dropped.
+pub(crate) fn report_to(sender: Sender<Event>) {
+    REPORTER.with(|reporter| *reporter.borrow_mut() = Some(sender));
+}
+
+/// Report progress to the thread's reporter, if there is one.
+pub(crate) fn report(event: Event) {
+    REPORTER.with(|reporter| {
+        if let Some(sender) = reporter.borrow().as_ref() {
+            let _ = sender.send(event);
+        }
+    });
+}
+
+/// Print output of a run, or send it to the thread's reporter.
+fn emit(output: String) {
+    let is_reported = REPORTER.with(|reporter| reporter.borrow().is_some());
+
+    if is_reported {
+        report(Event::Output(output));
+    } else {
+        write_output(output.as_bytes());
+    }
+}
+
+/// Where results are written once stdout was reserved for them, see [`reserve_stdout`].
+static RESULTS: OnceLock<File> = OnceLock::new();
+
+/// Keep stdout for results, e.g. the records of `--format json`. Everything else the process prints
+/// from then on goes to stderr, including the output of solutions and submissions.
+/// Only supported on unix, elsewhere everything stays on stdout.
+pub fn reserve_stdout() {
+    #[cfg(unix)]
+    {
+        use std::os::fd::FromRawFd;
+
+        if RESULTS.get().is_some() {
+            return;
+        }
+
+        let _ = stdout().flush();
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+        // SAFETY: duplicates the standard streams of this process, the copy of stdout is owned by the `File`.
+        unsafe {
+            let results = libc::dup(libc::STDOUT_FILENO);
+            if results < 0 {
+                return;
+            }
+            if libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+                libc::close(results);
+                return;
+            }
+            let _ = RESULTS.set(File::from_raw_fd(results));
+        }
+    }
+}
+
+/// Write results to stdout, or to where stdout was before it was reserved.
+pub(crate) fn write_output(output: &[u8]) {
+    let _ = ResultOutput.write_all(output);
+    let _ = ResultOutput.flush();
+}
+
+/// Stdout, or where stdout was before it was reserved by [`reserve_stdout`].
+pub(crate) struct ResultOutput;
+
+impl Write for ResultOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match RESULTS.get() {
+            Some(mut results) => results.write(buf),
+            None => stdout().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match RESULTS.get() {
+            Some(mut results) => results.flush(),
+            None => stdout().flush(),
+        }
+    }
+}
+
+/// Run a solution part, printing its progress and result if the output format is text.
+fn run_reported<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> (Result<Option<String>, String>, PartResult) {
     let part_str = format!("Part {part}");
+    let is_text = options.format == OutputFormat::Text;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, base_time, stats, memory) = run_timed(
+        func,
+        input,
+        |result| {
+            if is_text {
+                print_result(&result.answer(), &part_str, "");
+            }
+        },
+        &profile_path(year, day, &part.to_string()),
+        options,
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let result = result.answer();
+    let part_result = PartResult {
+        answer: result.clone().ok().flatten(),
+        duration: stats.as_ref().map_or(base_time, |s| s.mean),
+        samples: stats.as_ref().map_or(1, |s| s.samples),
+        stats,
+        memory,
+        error: result.clone().err(),
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if is_text {
+        let duration_str = format!(
+            "{}{}",
+            format_duration(part_result.duration, part_result.stats.as_ref()),
+            format_memory(part_result.memory.as_ref())
+        );
+        print_result(&result, &part_str, &duration_str);
     }
+
+    (result, part_result)
+}
+
+/// Where the heap profile of a part or stage is written to when running with `--dhat`.
+pub(crate) fn profile_path(year: Year, day: Day, name: &str) -> PathBuf {
+    data_dir(year)
+        .join("dhat")
+        .join(format!("{day}-{name}.json"))
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The function is executed once, and benched if `is_timed` is set.
+/// With the `dhat-heap` feature, the first execution is profiled, its profile written to `profile`
+/// and its heap usage returned.
+#[cfg_attr(not(feature = "dhat-heap"), allow(unused_variables))]
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
+    profile: &Path,
+    options: &RunOptions,
+) -> (T, Duration, Option<BenchStats>, Option<MemoryStats>) {
+    #[cfg(feature = "dhat-heap")]
+    let _ = profile.parent().map(std::fs::create_dir_all);
+
+    let (result, base_time, memory) = {
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::builder().file_name(profile).build();
+
+        // started after the profiler, so that setting it up is not part of the measured time.
+        let timer = Instant::now();
+        let result = func(input);
+        let base_time = timer.elapsed();
+
         #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+        let memory = Some(MemoryStats::from(dhat::HeapStats::get()));
+        #[cfg(not(feature = "dhat-heap"))]
+        let memory = None;
 
-        func(input)
+        (result, base_time, memory)
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
+    report(Event::Ran);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
+    let stats = options.is_timed.then(|| bench(func, input, options));
 
-    (result, run.0, run.1)
+    (result, base_time, stats, memory)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Bench a part after running the warmup iterations. Samples are taken until both `min_time` has passed
+/// and 10 samples were taken, unless `max_time` or 10.000 samples are reached first.
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, options: &RunOptions) -> BenchStats {
+    if options.format == OutputFormat::Text {
+        emit(format!(" > {ANSI_ITALIC}benching{ANSI_RESET}"));
+    }
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..options.bench.warmup {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let mut elapsed = Duration::ZERO;
 
-    for _ in 0..bench_iterations {
+    loop {
         let timer = Instant::now();
         black_box(func(black_box(input)));
-        timers.push(timer.elapsed());
+        let time = timer.elapsed();
+
+        timers.push(time);
+        elapsed += time;
+
+        let samples = timers.len() as u128;
+        let is_done = samples >= MAX_SAMPLES
+            || elapsed >= options.bench.max_time
+            || (samples >= MIN_SAMPLES && elapsed >= options.bench.min_time);
+
+        if is_done {
+            break;
+        }
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
+}
+
+fn format_duration(duration: Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) => format!(
+            " ({:.1?} @ {} samples; median {:.1?}, p95 {:.1?}, min {:.1?}, max {:.1?}, σ {:.1?})",
+            stats.mean, stats.samples, stats.median, stats.p95, stats.min, stats.max, stats.std_dev
+        ),
+    }
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_memory(memory: Option<&MemoryStats>) -> String {
+    memory.map_or_else(String::new, |memory| {
+        format!(
+            " [{} peak, {} in {} allocations]",
+            format_bytes(memory.peak_bytes),
+            format_bytes(usize::try_from(memory.total_bytes).unwrap_or(usize::MAX)),
+            memory.total_blocks
+        )
+    })
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub(crate) fn format_bytes(bytes: usize) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let value = bytes as f64;
+
+    if value >= 1024.0 * 1024.0 {
+        format!("{:.1} MiB", value / 1024.0 / 1024.0)
+    } else if value >= 1024.0 {
+        format!("{:.1} KiB", value / 1024.0)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!("{bytes} B")
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+pub(crate) fn print_result(
+    result: &Result<Option<String>, String>,
+    part: &str,
+    duration_str: &str,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    emit(str);
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    emit(format!("\r{str}\n{result}\n"));
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    emit(str);
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    emit(format!("\r{str}\n"));
                 }
             }
         }
-        None => {
+        Ok(None) => {
+            if is_intermediate_result {
+                emit(format!("{part}: ✖"));
+            } else {
+                emit(format!("\r{part}: ✖             \n"));
+            }
+        }
+        Err(error) => {
+            let str = format!("{part}: ✖ {ANSI_ITALIC}failed{ANSI_RESET}");
             if is_intermediate_result {
-                print!("{part}: ✖");
+                emit(str);
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                let error: String = error.lines().map(|line| format!("  {line}\n")).collect();
+                emit(format!("\r{str}{duration_str}\n{error}"));
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+/// The name of a stage as shown in text output, e.g. `Parse`.
+fn stage_label(stage: &str) -> String {
+    let mut chars = stage.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+fn print_stage(label: &str, duration_str: &str) {
+    if duration_str.is_empty() {
+        emit(format!("{label}: {ANSI_ITALIC}done{ANSI_RESET}"));
+    } else {
+        emit(format!(
+            "\r{label}: {ANSI_ITALIC}done{ANSI_RESET}{duration_str}\n"
+        ));
+    }
+}
+
+/// Build the JSON record for a stage, as printed with `--format json`.
+fn stage_record(year: Year, day: Day, stage: &str, result: &StageResult) -> JsonValue {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert("year".into(), JsonValue::Number(year.into_inner().into()));
+    map.insert("day".into(), JsonValue::String(day.to_string()));
+    map.insert("stage".into(), JsonValue::String(stage.into()));
+    #[allow(clippy::cast_precision_loss)]
+    map.insert(
+        "duration_nanos".into(),
+        JsonValue::Number(result.duration.as_nanos() as f64),
+    );
+    #[allow(clippy::cast_precision_loss)]
+    map.insert("samples".into(), JsonValue::Number(result.samples as f64));
+    map.insert(
+        "stats".into(),
+        result
+            .stats
+            .as_ref()
+            .map_or(JsonValue::Null, JsonValue::from),
+    );
+    map.insert(
+        "memory".into(),
+        result
+            .memory
+            .as_ref()
+            .map_or(JsonValue::Null, JsonValue::from),
+    );
+
+    JsonValue::Object(map)
+}
+
+/// Build the JSON record for a part, as printed with `--format json`.
+fn part_record(
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+    result: &PartResult,
+    status: PartStatus,
+) -> JsonValue {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert("year".into(), JsonValue::Number(year.into_inner().into()));
+    map.insert("day".into(), JsonValue::String(day.to_string()));
+    map.insert("part".into(), JsonValue::Number(part.into()));
+    map.insert(
+        "answer".into(),
+        result
+            .answer
+            .clone()
+            .map_or(JsonValue::Null, JsonValue::String),
+    );
+    #[allow(clippy::cast_precision_loss)]
+    map.insert(
+        "duration_nanos".into(),
+        JsonValue::Number(result.duration.as_nanos() as f64),
+    );
+    #[allow(clippy::cast_precision_loss)]
+    map.insert("samples".into(), JsonValue::Number(result.samples as f64));
+    map.insert("status".into(), JsonValue::String(status.as_str().into()));
+    map.insert(
+        "error".into(),
+        result
+            .error
+            .clone()
+            .map_or(JsonValue::Null, JsonValue::String),
+    );
+    map.insert(
+        "stats".into(),
+        result
+            .stats
+            .as_ref()
+            .map_or(JsonValue::Null, JsonValue::from),
+    );
+    map.insert(
+        "memory".into(),
+        result
+            .memory
+            .as_ref()
+            .map_or(JsonValue::Null, JsonValue::from),
+    );
+
+    JsonValue::Object(map)
+}
+
+/// Print a part's result as a single line of JSON.
+pub(crate) fn print_record(
+    year: Year,
+    day: Day,
+    part: u8,
+    result: &PartResult,
+    status: PartStatus,
+) {
+    print_json(part_record(year, day, part, result, status));
+}
+
+fn print_json(record: JsonValue) {
+    emit(json_line(&record));
+}
+
+fn json_line(record: &JsonValue) -> String {
+    match record.stringify() {
+        Ok(line) => format!("{line}\n"),
+        Err(e) => {
+            eprintln!("failed to serialize result: {e}");
+            String::new()
+        }
+    }
+}
+
+/// What to do with an answer, given what the ledger knows about it.
+#[derive(Debug, PartialEq)]
+enum Precheck {
+    /// Submit the answer, after printing the warning if there is one.
+    Submit(Option<String>),
+    /// Do not submit, the server already evaluated the answer.
+    Skip(String),
+}
+
+/// Only answers that were submitted before are skipped. An answer outside of the recorded bounds
+/// is most likely wrong, but the bounds are only as good as the answers they were recorded for.
+fn precheck(verdict: Verdict, answer: &str, part: u8) -> Precheck {
+    match verdict {
+        Verdict::Unknown => Precheck::Submit(None),
+        Verdict::Submitted(SubmissionResult::Correct) => Precheck::Skip(format!(
+            "{answer} was already accepted as the answer for part {part}."
+        )),
+        Verdict::Submitted(result) => Precheck::Skip(format!(
+            "Not submitting, {answer} was submitted before: {result}"
+        )),
+        Verdict::AboveTooHigh(bound) => Precheck::Submit(Some(format!(
+            "{answer} is not lower than {bound}, which was too high."
+        ))),
+        Verdict::BelowTooLow(bound) => Precheck::Submit(Some(format!(
+            "{answer} is not higher than {bound}, which was too low."
+        ))),
+    }
+}
+
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
+///  1. we are in `--release` mode.
+///  2. a session cookie is configured.
+///  3. the answer was not submitted before. Answers that break a too high / too low bound are submitted with a warning.
+fn submit_result<T: Display>(result: T, year: Year, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +1004,166 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    let answer = result.to_string();
+    let mut ledger = Ledger::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Not submitting, failed to read previous submissions: {e}");
         process::exit(1);
+    });
+
+    match precheck(ledger.check(day, part, &answer), &answer, part) {
+        Precheck::Submit(None) => {}
+        Precheck::Submit(Some(warning)) => eprintln!("Warning: {warning}"),
+        Precheck::Skip(message) => {
+            eprintln!("{message}");
+            return;
+        }
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    match aoc_client::submit(year, day, part, &answer) {
+        Ok(result) => {
+            println!("{result}");
+            ledger.record(day, part, &answer, result);
+            if let Err(e) = ledger.store_file(year) {
+                eprintln!("failed to store submission: {e}");
+            }
+        }
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{
+        BenchStats, OutputFormat, PartOutput, PartResult, PartStatus, Precheck, part_record,
+        precheck,
+    };
+    use crate::template::{aoc_client::SubmissionResult, ledger::Verdict};
+    use crate::{day, year};
+
+    #[test]
+    fn parses_output_formats() {
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn builds_part_records() {
+        let result = PartResult {
+            answer: Some("42".into()),
+            duration: Duration::from_micros(3),
+            samples: 10,
+            stats: None,
+            memory: None,
+            error: None,
+        };
+
+        let record = part_record(year!(2024), day!(1), 2, &result, result.status());
+        let map = record.get::<HashMap<String, JsonValue>>().unwrap();
+
+        assert_eq!(map["year"], JsonValue::Number(2024.0));
+        assert_eq!(map["day"], JsonValue::String("01".into()));
+        assert_eq!(map["part"], JsonValue::Number(2.0));
+        assert_eq!(map["answer"], JsonValue::String("42".into()));
+        assert_eq!(map["duration_nanos"], JsonValue::Number(3000.0));
+        assert_eq!(map["samples"], JsonValue::Number(10.0));
+        assert_eq!(map["status"], JsonValue::String("solved".into()));
+    }
+
+    #[test]
+    fn builds_records_for_missing_answers() {
+        let result = PartResult {
+            answer: None,
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: None,
+            memory: None,
+            error: None,
+        };
+
+        let record = part_record(year!(2024), day!(1), 1, &result, PartStatus::Panicked);
+        let map = record.get::<HashMap<String, JsonValue>>().unwrap();
+
+        assert_eq!(map["answer"], JsonValue::Null);
+        assert_eq!(map["status"], JsonValue::String("panicked".into()));
+        assert_eq!(map["error"], JsonValue::Null);
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        assert_eq!(Some(42).answer(), Ok(Some("42".into())));
+        assert_eq!(None::<u64>.answer(), Ok(None));
+        assert_eq!(Ok::<u64, String>(7).answer(), Ok(Some("7".into())));
+        assert_eq!(Ok::<Option<u64>, String>(None).answer(), Ok(None));
+        assert_eq!(
+            Err::<u64, _>("line 1, column 1: bad input").answer(),
+            Err("line 1, column 1: bad input".into())
+        );
+    }
+
+    #[test]
+    fn computes_bench_stats() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3, 100]
+            .into_iter()
+            .map(Duration::from_nanos)
+            .collect();
+
+        let stats = BenchStats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.mean, Duration::from_nanos(19));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.std_dev, Duration::from_nanos(40));
+    }
+
+    #[test]
+    fn computes_bench_stats_for_single_sample() {
+        let stats = BenchStats::from_samples(&[Duration::from_nanos(7)]);
+        assert_eq!(stats.median, Duration::from_nanos(7));
+        assert_eq!(stats.p95, Duration::from_nanos(7));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn submits_answers_that_break_bounds_with_a_warning() {
+        assert_eq!(precheck(Verdict::Unknown, "42", 1), Precheck::Submit(None));
+        assert_eq!(
+            precheck(Verdict::AboveTooHigh("40".into()), "42", 1),
+            Precheck::Submit(Some("42 is not lower than 40, which was too high.".into()))
+        );
+        assert_eq!(
+            precheck(Verdict::BelowTooLow("50".into()), "42", 1),
+            Precheck::Submit(Some("42 is not higher than 50, which was too low.".into()))
+        );
+    }
+
+    #[test]
+    fn skips_answers_that_were_submitted_before() {
+        assert_eq!(
+            precheck(Verdict::Submitted(SubmissionResult::Incorrect), "42", 1),
+            Precheck::Skip(
+                "Not submitting, 42 was submitted before: That's not the right answer.".into()
+            )
+        );
+        assert!(matches!(
+            precheck(Verdict::Submitted(SubmissionResult::Correct), "42", 2),
+            Precheck::Skip(_)
+        ));
+    }
 }
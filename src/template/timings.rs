@@ -1,9 +1,10 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::runner::{BenchStats, MemoryStats, PartStatus};
+use crate::template::{Day, Year, data_dir};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,9 +13,51 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Whether the parts ran to an answer, panicked or timed out. Not present in timings stored by older versions.
+    pub part_1_status: Option<PartStatus>,
+    pub part_2_status: Option<PartStatus>,
+    /// Time spent in the `parse` stage, for solutions that declare one.
+    pub parse: Option<String>,
+    pub parse_stats: Option<BenchStats>,
+    /// Time spent in the `prepare` stage, for solutions that share work between the parts.
+    pub prepare: Option<String>,
+    pub prepare_stats: Option<BenchStats>,
+    /// Heap usage of the stages and parts, recorded by `cargo time --memory`.
+    pub parse_memory: Option<MemoryStats>,
+    pub prepare_memory: Option<MemoryStats>,
+    pub part_1_memory: Option<MemoryStats>,
+    pub part_2_memory: Option<MemoryStats>,
+    /// When the timing was taken, as seconds since the unix epoch.
+    pub timestamp: Option<u64>,
+    /// The git commit the timing was taken at.
+    pub commit: Option<String>,
 }
 
-/// Represents benchmark times for a set of days.
+impl Timing {
+    /// The largest heap usage of any stage or part, if the timing recorded memory.
+    pub fn peak_memory(&self) -> Option<usize> {
+        [
+            &self.parse_memory,
+            &self.prepare_memory,
+            &self.part_1_memory,
+            &self.part_2_memory,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|memory| memory.peak_bytes)
+        .max()
+    }
+
+    /// Whether the timing was taken by `cargo time --memory`. Those runs use the profiling allocator,
+    /// so their durations are not comparable to those of other runs.
+    pub fn is_memory_run(&self) -> bool {
+        self.peak_memory().is_some()
+    }
+}
+
+/// Represents benchmark times for a set of days, possibly several runs per day.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
@@ -23,45 +66,70 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(data_dir(year).join(TIMINGS_FILE_NAME))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(data_dir(year).join(TIMINGS_FILE_NAME))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Merge two sets of timings, appending `new` to the history of `self`.
     pub fn merge(&self, new: &Self) -> Self {
-        let mut data: Vec<Timing> = vec![];
+        let mut data: Vec<Timing> = self.data.clone();
+        data.extend(new.data.iter().cloned());
 
-        for timing in &new.data {
-            data.push(timing.clone());
-        }
+        // NOTE: stable sort, runs of a day stay in the order they were recorded.
+        data.sort_by_key(|a| a.day);
+        Timings { data }
+    }
 
-        for timing in &self.data {
+    /// The most recent timing of every day.
+    pub fn latest(&self) -> Self {
+        let mut data: Vec<Timing> = vec![];
+
+        for timing in self.data.iter().rev() {
             if !data.iter().any(|t| t.day == timing.day) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
+    /// All recorded timings of a day, oldest first.
+    pub fn history(&self, day: Day) -> Vec<&Timing> {
+        self.data.iter().filter(|t| t.day == day).collect()
+    }
+
+    /// The runs that were timed without the profiling allocator of `--memory`.
+    pub fn benchmarks(&self) -> Self {
+        Timings {
+            data: self
+                .data
+                .iter()
+                .filter(|t| !t.is_memory_run())
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Whether a run without `--memory` timed both parts of a day.
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
+        self.benchmarks()
+            .data
             .iter()
             .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
@@ -133,6 +201,59 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, time) in [
+            ("parse", &value.parse),
+            ("prepare", &value.prepare),
+            ("commit", &value.commit),
+        ] {
+            map.insert(
+                key.into(),
+                time.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+            ("parse_stats", &value.parse_stats),
+            ("prepare_stats", &value.prepare_stats),
+        ] {
+            map.insert(
+                key.into(),
+                stats.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        for (key, memory) in [
+            ("parse_memory", &value.parse_memory),
+            ("prepare_memory", &value.prepare_memory),
+            ("part_1_memory", &value.part_1_memory),
+            ("part_2_memory", &value.part_2_memory),
+        ] {
+            map.insert(
+                key.into(),
+                memory.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        for (key, status) in [
+            ("part_1_status", value.part_1_status),
+            ("part_2_status", value.part_2_status),
+        ] {
+            map.insert(
+                key.into(),
+                status.map_or(JsonValue::Null, |s| JsonValue::String(s.as_str().into())),
+            );
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            value
+                .timestamp
+                .map_or(JsonValue::Null, |t| JsonValue::Number(t as f64)),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +287,170 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // stages, statistics and run metadata are optional, timings stored by older versions do not have them.
+        let optional_string = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                None => Ok(None),
+                Some(v) if v.is_null() => Ok(None),
+                Some(v) => v
+                    .get::<String>()
+                    .cloned()
+                    .map(Some)
+                    .ok_or(format!("Expected timing.{key} to be null or string.")),
+            }
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = match json.get("timestamp") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|t| *t as u64)
+                    .ok_or("Expected timing.timestamp to be null or a number.")?,
+            ),
+        };
+
+        let stats = |key: &str| -> Result<Option<BenchStats>, String> {
+            match json.get(key) {
+                None => Ok(None),
+                Some(v) if v.is_null() => Ok(None),
+                Some(v) => BenchStats::try_from(v).map(Some),
+            }
+        };
+
+        let memory = |key: &str| -> Result<Option<MemoryStats>, String> {
+            match json.get(key) {
+                None => Ok(None),
+                Some(v) if v.is_null() => Ok(None),
+                Some(v) => MemoryStats::try_from(v).map(Some),
+            }
+        };
+
+        let status = |key: &str| -> Result<Option<PartStatus>, String> {
+            optional_string(key)?
+                .map(|s| {
+                    s.parse()
+                        .map_err(|e| format!("Expected timing.{key} to be a status: {e}."))
+                })
+                .transpose()
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            part_1_status: status("part_1_status")?,
+            part_2_status: status("part_2_status")?,
+            parse: optional_string("parse")?,
+            parse_stats: stats("parse_stats")?,
+            prepare: optional_string("prepare")?,
+            prepare_stats: stats("prepare_stats")?,
+            parse_memory: memory("parse_memory")?,
+            prepare_memory: memory("prepare_memory")?,
+            part_1_memory: memory("part_1_memory")?,
+            part_2_memory: memory("part_2_memory")?,
+            timestamp,
+            commit: optional_string("commit")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = |d: std::time::Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("max_nanos".into(), nanos(value.max));
+        map.insert("std_dev_nanos".into(), nanos(value.std_dev));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nanos = |key: &str| number(key).map(|n| std::time::Duration::from_nanos(n as u64));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: nanos("mean_nanos")?,
+            median: nanos("median_nanos")?,
+            min: nanos("min_nanos")?,
+            max: nanos("max_nanos")?,
+            std_dev: nanos("std_dev_nanos")?,
+            p95: nanos("p95_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&MemoryStats> for JsonValue {
+    fn from(value: &MemoryStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        for (key, number) in [
+            ("total_bytes", value.total_bytes as f64),
+            ("total_blocks", value.total_blocks as f64),
+            ("peak_bytes", value.peak_bytes as f64),
+            ("peak_blocks", value.peak_blocks as f64),
+        ] {
+            map.insert(key.into(), JsonValue::Number(number));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(MemoryStats {
+            total_bytes: number("total_bytes")? as u64,
+            total_blocks: number("total_blocks")? as u64,
+            peak_bytes: number("peak_bytes")? as usize,
+            peak_blocks: number("peak_blocks")? as usize,
         })
     }
 }
@@ -191,25 +471,72 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{runner::PartStatus, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +550,58 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "2ms", "part_2": null, "total_nanos": 2000000, "part_1_stats": { "samples": 10, "mean_nanos": 2000000, "median_nanos": 1900000, "min_nanos": 1000000, "max_nanos": 5000000, "std_dev_nanos": 100000, "p95_nanos": 4000000 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.median, Duration::from_micros(1900));
+            assert_eq!(stats.p95, Duration::from_millis(4));
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_parse_stage() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "parse": "2ms", "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("2ms".to_string()));
+            assert_eq!(timing.parse_stats, None);
+        }
+
+        #[test]
+        fn handles_json_run_metadata() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "timestamp": 1765188900, "commit": "abc1234" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.timestamp, Some(1_765_188_900));
+            assert_eq!(timing.commit, Some("abc1234".to_string()));
+        }
+
+        #[test]
+        fn handles_json_memory() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "parse_memory": { "total_bytes": 4096, "total_blocks": 12, "peak_bytes": 2048, "peak_blocks": 3 }, "part_1_memory": { "total_bytes": 512, "total_blocks": 2, "peak_bytes": 512, "peak_blocks": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse_memory.unwrap().total_blocks, 12);
+            assert_eq!(timing.part_2_memory, None);
+            assert_eq!(timing.peak_memory(), Some(2048));
+        }
+
+        #[test]
+        fn handles_json_part_statuses() {
+            let json = r#"{ "data": [{ "day": "10", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_status": "solved", "part_2_status": "timed_out" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_status, Some(PartStatus::Solved));
+            assert_eq!(timing.part_2_status, Some(PartStatus::TimedOut));
+
+            let json = r#"{ "data": [{ "day": "10", "part_1": null, "part_2": null, "total_nanos": 0, "part_1_status": "hung" }] }"#.to_string();
+            assert!(Timings::try_from(json).is_err());
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -271,7 +650,10 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                runner::MemoryStats,
+                timings::{Timing, Timings},
+            },
         };
 
         #[test]
@@ -282,10 +664,24 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -296,10 +692,24 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,10 +720,58 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_memory_runs() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    total_nanos: 3_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: Some(MemoryStats {
+                        total_bytes: 4096,
+                        total_blocks: 12,
+                        peak_bytes: 1024,
+                        peak_blocks: 4,
+                    }),
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+            assert!(timings.benchmarks().data.is_empty());
         }
     }
 
@@ -334,6 +792,20 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,15 +826,34 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    parse: None,
+                    parse_stats: None,
+                    prepare: None,
+                    prepare_stats: None,
+                    parse_memory: None,
+                    prepare_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    timestamp: None,
+                    commit: None,
                 }],
             };
             let merged = timings.merge(&other);
 
-            assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.history(day!(2)).len(), 2);
+            assert_eq!(merged.history(day!(2))[1].total_nanos, 0_f64);
+
+            let latest = merged.latest();
+            assert_eq!(latest.data.len(), 3);
+            assert_eq!(latest.data[0].day, day!(1));
+            assert_eq!(latest.data[1].day, day!(2));
+            assert_eq!(latest.data[1].total_nanos, 0_f64);
+            assert_eq!(latest.data[2].day, day!(4));
         }
 
         #[test]
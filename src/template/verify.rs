@@ -0,0 +1,164 @@
+/// Module that checks the answers of a run against the accepted answers in the ledger.
+use crate::template::ledger::Ledger;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// The answers a day's solution produced in a run.
+#[derive(Clone, Debug)]
+pub struct Answers {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            _ => self.part_2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No accepted answer is known for this part.
+    Unverified,
+}
+
+impl Check {
+    fn describe(&self) -> String {
+        match self {
+            Check::Pass => "✔ pass".into(),
+            Check::Fail { expected, actual } => format!(
+                "✖ expected `{expected}`, got {}",
+                actual
+                    .as_ref()
+                    .map_or_else(|| "nothing".into(), |a| format!("`{a}`"))
+            ),
+            Check::Unverified => "-".into(),
+        }
+    }
+}
+
+pub fn check(ledger: &Ledger, answers: &Answers, part: u8) -> Check {
+    let Some(expected) = ledger.accepted(answers.day, part) else {
+        return Check::Unverified;
+    };
+
+    match answers.get(part) {
+        Some(actual) if actual == expected => Check::Pass,
+        actual => Check::Fail {
+            expected: expected.into(),
+            actual: actual.cloned(),
+        },
+    }
+}
+
+/// Check a run without printing anything. Returns `false` if any part regressed.
+pub fn passes(ledger: &Ledger, answers: &[Answers]) -> bool {
+    answers.iter().all(|day_answers| {
+        [1, 2]
+            .iter()
+            .all(|&part| !matches!(check(ledger, day_answers, part), Check::Fail { .. }))
+    })
+}
+
+/// Print a pass / fail table for a run. Returns `false` if any part regressed.
+pub fn verify(ledger: &Ledger, answers: &[Answers]) -> bool {
+    let mut failed = 0;
+    let mut passed = 0;
+
+    println!("\n{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------------");
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: |");
+
+    for day_answers in answers {
+        let checks = [1, 2].map(|part| check(ledger, day_answers, part));
+
+        for c in &checks {
+            match c {
+                Check::Pass => passed += 1,
+                Check::Fail { .. } => failed += 1,
+                Check::Unverified => {}
+            }
+        }
+
+        println!(
+            "| {} | {} | {} |",
+            day_answers.day,
+            checks[0].describe(),
+            checks[1].describe()
+        );
+    }
+
+    println!("\n{passed} passed, {failed} failed.");
+    failed == 0
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Check, check};
+    use crate::{
+        day,
+        template::{aoc_client::SubmissionResult, ledger::Ledger},
+    };
+
+    fn get_mock_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "3", SubmissionResult::TooLow);
+        ledger.record(day!(1), 1, "42", SubmissionResult::Correct);
+        ledger.accept(day!(1), 2, "7");
+        ledger
+    }
+
+    #[test]
+    fn checks_answers() {
+        let ledger = get_mock_ledger();
+        let answers = Answers {
+            day: day!(1),
+            part_1: Some("42".into()),
+            part_2: Some("8".into()),
+        };
+
+        assert_eq!(check(&ledger, &answers, 1), Check::Pass);
+        assert_eq!(
+            check(&ledger, &answers, 2),
+            Check::Fail {
+                expected: "7".into(),
+                actual: Some("8".into())
+            }
+        );
+    }
+
+    #[test]
+    fn handles_missing_answers() {
+        let ledger = get_mock_ledger();
+        let answers = Answers {
+            day: day!(2),
+            part_1: None,
+            part_2: None,
+        };
+        assert_eq!(check(&ledger, &answers, 1), Check::Unverified);
+
+        let answers = Answers {
+            day: day!(1),
+            part_1: None,
+            part_2: None,
+        };
+        assert_eq!(
+            check(&ledger, &answers, 1),
+            Check::Fail {
+                expected: "42".into(),
+                actual: None
+            }
+        );
+    }
+}
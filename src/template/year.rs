@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+
+use crate::template::MAX_DAY;
+
+/// The first year of advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// The number of days of the events set with `AOC_DAY_COUNTS`, by year. Read on first use.
+static DAY_COUNTS: LazyLock<RwLock<HashMap<Year, u8>>> = LazyLock::new(|| {
+    let counts = env::var("AOC_DAY_COUNTS")
+        .map_or_else(|_| Ok(HashMap::new()), |setting| parse_day_counts(&setting));
+
+    RwLock::new(counts.unwrap_or_else(|e| {
+        eprintln!("Ignoring `AOC_DAY_COUNTS`: {e}");
+        HashMap::new()
+    }))
+});
+
+/// Parse a setting of the number of days per year, e.g. `2025=12,2026=12`.
+fn parse_day_counts(setting: &str) -> Result<HashMap<Year, u8>, String> {
+    setting
+        .split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (year, days) = entry
+                .split_once('=')
+                .ok_or(format!("expected `<year>=<days>`, got `{entry}`."))?;
+            let year = year.trim().parse::<Year>().map_err(|e| e.to_string())?;
+            let days = days
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|days| (1..=MAX_DAY).contains(days))
+                .ok_or(format!("expected between 1 and {MAX_DAY} days for {year}."))?;
+            Ok((year, days))
+        })
+        .collect()
+}
+
+/// A year of advent of code, from 2015 onwards.
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// The year set with `AOC_YEAR` when the template was compiled, see `.cargo/config.toml`.
+    /// Commands use it when no `--year` is passed, and solutions that don't declare a year belong to it.
+    pub const fn configured() -> Option<Self> {
+        let Some(value) = option_env!("AOC_YEAR") else {
+            return None;
+        };
+
+        let bytes = value.as_bytes();
+        if bytes.is_empty() || bytes.len() > 4 {
+            return None;
+        }
+
+        let mut year = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+
+    /// The number of days of the year's event, as set with `AOC_DAY_COUNTS`.
+    /// Years that are not set run for 12 days from 2025 on and for 25 days before.
+    pub fn day_count(self) -> u8 {
+        let counts = DAY_COUNTS.read().unwrap_or_else(|e| e.into_inner());
+        counts
+            .get(&self)
+            .copied()
+            .unwrap_or(if self.0 >= 2025 { 12 } else { MAX_DAY })
+    }
+
+    /// Change the number of days of the year's event for the rest of the process, like `AOC_DAY_COUNTS`.
+    #[cfg(feature = "test_lib")]
+    pub(crate) fn set_day_count(self, days: u8) {
+        let mut counts = DAY_COUNTS.write().unwrap_or_else(|e| e.into_inner());
+        counts.insert(self, days);
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Year, parse_day_counts};
+    use crate::year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Year::new(2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+        assert!("next".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_day_counts() {
+        let counts = parse_day_counts("2025=12, 2026=10").unwrap();
+        assert_eq!(counts[&year!(2025)], 12);
+        assert_eq!(counts[&year!(2026)], 10);
+        assert!(parse_day_counts("").unwrap().is_empty());
+
+        assert!(parse_day_counts("2025").is_err());
+        assert!(parse_day_counts("2025=0").is_err());
+        assert!(parse_day_counts("2025=26").is_err());
+        assert!(parse_day_counts("25=12").is_err());
+    }
+
+    #[test]
+    fn defaults_day_counts() {
+        assert_eq!(year!(2024).day_count(), 25);
+        assert_eq!(year!(2025).day_count(), 12);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,239 @@
+//! Disjoint sets that are merged with [`UnionFind::union`], e.g. to track connected components
+//! while edges are added one at a time.
+//!
+//! ```ignore
+//! use advent_of_code::union_find::UnionFind;
+//!
+//! let mut sets = UnionFind::new(4);
+//! sets.union(0, 1);
+//! sets.union(2, 3);
+//! assert!(sets.connected(1, 0));
+//! assert_eq!(sets.components(), 2);
+//! ```
+use std::hash::Hash;
+
+use fxhash::FxHashMap;
+
+/// Disjoint sets of the elements `0..len`, with path compression and union by size.
+#[derive(Clone, Debug, Default)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// The size of the set of every root, stale for the other elements.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `len` elements, each in a set of its own.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Add an element in a set of its own and return its index.
+    pub fn add(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.components += 1;
+        element
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set that contains `element`.
+    ///
+    /// # Panics
+    /// If `element` is not less than [`UnionFind::len`].
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point every element on the way at the root, so the next lookup is a single step.
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Merge the sets of `a` and `b`. Returns `false` if they already were in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.size[a] < self.size[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the set of `element`.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// The number of disjoint sets.
+    #[must_use]
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|&(element, &parent)| element == parent)
+            .map(|(root, _)| self.size[root])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Disjoint sets of arbitrary values. A value joins as a set of its own the first time it is used.
+#[derive(Clone, Debug)]
+pub struct KeyedUnionFind<K> {
+    indices: FxHashMap<K, usize>,
+    sets: UnionFind,
+}
+
+impl<K: Eq + Hash> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        KeyedUnionFind {
+            indices: FxHashMap::default(),
+            sets: UnionFind::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> KeyedUnionFind<K> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `key` in a set of its own, unless it is already known. Returns its index.
+    pub fn insert(&mut self, key: K) -> usize {
+        *self.indices.entry(key).or_insert_with(|| self.sets.add())
+    }
+
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+
+    /// Merge the sets of `a` and `b`, adding them if they are new. Returns `false` if they already were in the same set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Whether `a` and `b` are in the same set. Unknown keys are only connected to themselves.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.connected(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// The number of values in the set of `key`, `None` if it is unknown.
+    pub fn size(&mut self, key: &K) -> Option<usize> {
+        let &index = self.indices.get(key)?;
+        Some(self.sets.size(index))
+    }
+
+    /// The number of disjoint sets.
+    #[must_use]
+    pub fn components(&self) -> usize {
+        self.sets.components()
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> {
+        self.sets.component_sizes()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{KeyedUnionFind, UnionFind};
+
+    #[test]
+    fn merges_sets() {
+        let mut sets = UnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 1));
+        assert!(sets.union(4, 5));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 2));
+        assert!(!sets.connected(0, 3));
+        assert_eq!(sets.size(1), 3);
+        assert_eq!(sets.components(), 3);
+
+        let mut sizes: Vec<_> = sets.component_sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 3]);
+
+        assert_eq!(sets.add(), 6);
+        assert_eq!(sets.components(), 4);
+    }
+
+    #[test]
+    fn merges_keyed_sets() {
+        let mut sets = KeyedUnionFind::new();
+
+        assert!(sets.union("a", "b"));
+        assert!(sets.union("c", "d"));
+        assert!(sets.union("b", "d"));
+        sets.insert("e");
+
+        assert!(sets.connected(&"a", &"c"));
+        assert!(!sets.connected(&"a", &"e"));
+        assert!(!sets.connected(&"a", &"z"));
+        assert_eq!(sets.size(&"d"), Some(4));
+        assert_eq!(sets.size(&"z"), None);
+        assert_eq!((sets.len(), sets.components()), (5, 2));
+    }
+}